@@ -2,16 +2,19 @@ use bevy::prelude::*;
 use std::time::Duration;
 
 use crate::{
-    background, chair, fireplace, flickering_light, house, house_lights, interaction, santa, snow, snowman, stereo,
-    theman, tree,
+    background, chair, couch, doorbell, fireplace, flickering_light, house, house_lights,
+    interaction::{self, DisabledProps},
+    santa, snow, snowman, stereo, theman, tree, y_sort,
 };
 
+// Frame range and timing of a sprite animation, reversed animations play from the last frame toward the first.
 #[derive(Component)]
 pub struct AnimationConfig {
     pub first_index: usize,
     pub last_index: usize,
     pub fps: u8,
     pub frame_timer: Timer,
+    pub reverse: bool,
 }
 
 impl AnimationConfig {
@@ -21,27 +24,175 @@ impl AnimationConfig {
             last_index: last,
             fps,
             frame_timer: Self::timer_from_fps(fps),
+            reverse: false,
         }
     }
 
+    // Config covering every frame of the atlas layout, None when the layout has no frames.
+    pub fn from_layout(layout: &TextureAtlasLayout, fps: u8) -> Option<Self> {
+        layout.len().checked_sub(1).map(|last| Self::new(0, last, fps))
+    }
+
+    // Check the frame range is ordered and fits within the atlas layout.
+    pub fn fits(&self, layout: &TextureAtlasLayout) -> bool {
+        self.first_index <= self.last_index && self.last_index < layout.len()
+    }
+
+    // Next frame of a looping animation, wrapping around at the end it plays toward.
+    pub fn next_loop(&self, index: usize) -> usize {
+        if self.reverse {
+            if index <= self.first_index {
+                self.last_index
+            } else {
+                index - 1
+            }
+        } else if index >= self.last_index {
+            self.first_index
+        } else {
+            index + 1
+        }
+    }
+
+    // Next frame of an animation that plays once, None once it's reached the end it plays toward.
+    pub fn next_once(&self, index: usize) -> Option<usize> {
+        if self.reverse {
+            (index > self.first_index).then(|| index - 1)
+        } else {
+            (index < self.last_index).then(|| index + 1)
+        }
+    }
+
+    // Frame timer for the fps, a zero fps is clamped to one frame a second rather than a timer that never fires.
     pub fn timer_from_fps(fps: u8) -> Timer {
-        Timer::new(Duration::from_secs_f32(1.0 / f32::from(fps)), TimerMode::Once)
+        Timer::new(Duration::from_secs_f32(1.0 / f32::from(fps.max(1))), TimerMode::Once)
     }
 }
 
-// Add the animation systems.
+// Entity whose animation is held for frame stepping in debug builds.
+#[cfg(debug_assertions)]
+#[derive(Default, Resource)]
+struct FrameStep {
+    target: Option<Entity>,
+}
+
+// Add the animation systems, disabled props are skipped entirely so none of their systems run.
 pub fn add_systems(app: &mut App) {
-    house_lights::add_systems(app);
+    let disabled = app
+        .init_resource::<DisabledProps>()
+        .world()
+        .resource::<DisabledProps>()
+        .clone();
+
+    if !disabled.contains(house_lights::INTERACTABLE_ID) {
+        house_lights::add_systems(app);
+    }
     background::add_systems(app);
-    chair::add_systems(app);
+    if !disabled.contains(chair::INTERACTABLE_ID) {
+        chair::add_systems(app);
+    }
+    if !disabled.contains(couch::INTERACTABLE_ID) {
+        couch::add_systems(app);
+    }
+    if !disabled.contains(doorbell::INTERACTABLE_ID) {
+        doorbell::add_systems(app);
+    }
     interaction::add_systems(app);
     flickering_light::add_systems(app);
     house::add_systems(app);
-    fireplace::add_systems(app);
+    if !disabled.contains(fireplace::INTERACTABLE_ID) {
+        fireplace::add_systems(app);
+    }
     santa::add_systems(app);
     snow::add_systems(app);
     snowman::add_systems(app);
-    stereo::add_systems(app);
+    if !disabled.contains(stereo::INTERACTABLE_ID) {
+        stereo::add_systems(app);
+    }
     theman::add_systems(app);
-    tree::add_systems(app);
+    if !disabled.contains(tree::INTERACTABLE_ID) {
+        tree::add_systems(app);
+    }
+    y_sort::add_systems(app);
+
+    // Debug builds can hold an animation and step through its frames by hand.
+    #[cfg(debug_assertions)]
+    app.init_resource::<FrameStep>().add_systems(Update, handle_frame_step);
+}
+
+// Cycle the held animation with F10 through everything showing an atlas, and back to none which lets it play again.
+// The held animation's timer stays paused and comma and period step it a frame back or forward within its range.
+#[cfg(debug_assertions)]
+fn handle_frame_step(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut step: ResMut<FrameStep>,
+    mut query: Query<(Entity, &mut AnimationConfig, &mut Sprite)>,
+) {
+    if keyboard.just_pressed(KeyCode::F10) {
+        if let Some(target) = step.target
+            && let Ok((_, mut config, _)) = query.get_mut(target)
+        {
+            config.frame_timer.unpause();
+        }
+
+        let mut entities: Vec<Entity> = query
+            .iter()
+            .filter(|(_, _, sprite)| sprite.texture_atlas.is_some())
+            .map(|(entity, _, _)| entity)
+            .collect();
+        entities.sort();
+        step.target = match step.target {
+            Some(target) => entities.into_iter().find(|&entity| entity > target),
+            None => entities.first().copied(),
+        };
+        debug!("frame stepping {:?}", step.target);
+    }
+
+    let Some(target) = step.target else {
+        return;
+    };
+    let Ok((_, mut config, mut sprite)) = query.get_mut(target) else {
+        step.target = None;
+        return;
+    };
+
+    // Animations swap in fresh timers as they change state, so the pause is applied every frame.
+    config.frame_timer.pause();
+
+    let Some(atlas) = &mut sprite.texture_atlas else {
+        return;
+    };
+    if keyboard.just_pressed(KeyCode::Period) {
+        atlas.index = if atlas.index >= config.last_index {
+            config.first_index
+        } else {
+            atlas.index + 1
+        };
+        debug!(
+            "frame {} of {}..={}",
+            atlas.index, config.first_index, config.last_index
+        );
+    } else if keyboard.just_pressed(KeyCode::Comma) {
+        atlas.index = if atlas.index <= config.first_index {
+            config.last_index
+        } else {
+            atlas.index - 1
+        };
+        debug!(
+            "frame {} of {}..={}",
+            atlas.index, config.first_index, config.last_index
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_fps_runs_at_one_frame_a_second() {
+        let config = AnimationConfig::new(0, 4, 0);
+
+        assert_eq!(config.frame_timer.duration(), Duration::from_secs(1));
+        assert_eq!(AnimationConfig::timer_from_fps(0).duration(), Duration::from_secs(1));
+    }
 }
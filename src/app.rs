@@ -1,26 +1,246 @@
+#[cfg(not(target_arch = "wasm32"))]
+use bevy::render::view::screenshot::{Screenshot, ScreenshotCaptured, save_to_disk};
 use bevy::{
+    app::PluginGroupBuilder,
     audio::{AudioPlugin, SpatialScale},
+    ecs::{schedule::ScheduleLabel, system::ScheduleSystem},
     prelude::*,
 };
 use bevy_light_2d::prelude::*;
+#[cfg(not(target_arch = "wasm32"))]
+use std::{
+    path::PathBuf,
+    time::{Duration, Instant},
+};
 
-use crate::{animation, camera, input};
+use crate::{animation, attract, audio, camera, input, sampling};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::{
+    camera::{ExportRender, ExportTarget},
+    fireplace, house_lights,
+    interaction::{InitialStates, State},
+    replay, tree,
+};
 
 const AUDIO_SCALE: f32 = 1. / 200.;
 
+// Frames the scene runs for before a thumbnail is captured, long enough for the lights to settle in.
+#[cfg(not(target_arch = "wasm32"))]
+const THUMBNAIL_FRAMES: u32 = 120;
+
+// Props switched on for thumbnails so the card shows with every light lit.
+#[cfg(not(target_arch = "wasm32"))]
+const THUMBNAIL_LIGHT_IDS: [&str; 3] = [
+    fireplace::INTERACTABLE_ID,
+    house_lights::INTERACTABLE_ID,
+    tree::INTERACTABLE_ID,
+];
+
+type Extension = Box<dyn Fn(&mut App) + Send + Sync>;
+
+// Frame rate cap to save power, toggled with the toggle key. Motion is driven by the frame delta so only the CPU and
+// GPU usage changes.
+// NOTE: browsers already pace frames to the display, so the cap only applies to desktop builds.
+#[derive(Clone, Resource)]
+pub struct FrameLimit {
+    pub enabled: bool,
+    pub fps: u32,
+    pub toggle_key: KeyCode,
+}
+
+impl Default for FrameLimit {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            fps: 30,
+            toggle_key: KeyCode::F7,
+        }
+    }
+}
+
+// Where generate_thumbnail saves its capture.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Resource)]
+struct ThumbnailCapture {
+    path: PathBuf,
+}
+
+// The holiday card scene, external code can register additional interactables and systems.
+#[derive(Default)]
+pub struct HolidayCardPlugin {
+    extensions: Vec<Extension>,
+}
+
+impl HolidayCardPlugin {
+    // Register a startup system that spawns an additional interactable.
+    pub fn with_interactable<M>(
+        self,
+        spawn: impl IntoScheduleConfigs<ScheduleSystem, M> + Clone + Send + Sync + 'static,
+    ) -> Self {
+        self.with_systems(Startup, spawn)
+    }
+
+    // Register additional systems in the given schedule.
+    pub fn with_systems<M>(
+        mut self,
+        schedule: impl ScheduleLabel,
+        systems: impl IntoScheduleConfigs<ScheduleSystem, M> + Clone + Send + Sync + 'static,
+    ) -> Self {
+        let schedule = schedule.intern();
+        self.extensions.push(Box::new(move |app: &mut App| {
+            app.add_systems(schedule, systems.clone());
+        }));
+        self
+    }
+}
+
+impl Plugin for HolidayCardPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FrameLimit>();
+        #[cfg(not(target_arch = "wasm32"))]
+        app.add_systems(Last, handle_frame_limit);
+
+        camera::add_systems(app);
+        sampling::add_systems(app);
+        input::add_systems(app);
+        attract::add_systems(app);
+        // Replays are saved to a file, which isn't available in the browser.
+        #[cfg(not(target_arch = "wasm32"))]
+        replay::add_systems(app);
+        audio::add_systems(app);
+        animation::add_systems(app);
+
+        for extension in &self.extensions {
+            extension(app);
+        }
+    }
+}
+
 pub fn run_app() {
     let mut app = App::new();
 
     app.add_plugins((
-        DefaultPlugins.set(ImagePlugin::default_nearest()).set(AudioPlugin {
-            default_spatial_scale: SpatialScale::new_2d(AUDIO_SCALE),
-            ..default()
-        }),
+        default_plugins(primary_window()),
         Light2dPlugin,
+        HolidayCardPlugin::default(),
     ));
-    camera::add_systems(&mut app);
-    input::add_systems(&mut app);
-    animation::add_systems(&mut app);
 
     app.run();
 }
+
+// Run the scene with every light on in a hidden window, save a capture of it at the given size to path and exit. The
+// capture comes from the export render so it doesn't depend on the window size.
+// NOTE: it's a full windowed app and winit only allows one event loop per process, so call it instead of run() rather
+// than after it, and only once. Calling it a second time panics.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn generate_thumbnail(path: impl Into<PathBuf>, size: UVec2) -> AppExit {
+    let mut app = App::new();
+
+    app.insert_resource(ExportRender {
+        enabled: true,
+        width: size.x,
+        height: size.y,
+    })
+    .insert_resource(InitialStates(
+        THUMBNAIL_LIGHT_IDS
+            .iter()
+            .map(|id| (id.to_string(), State::On))
+            .collect(),
+    ))
+    .insert_resource(ThumbnailCapture { path: path.into() })
+    .add_plugins((
+        default_plugins(Window {
+            visible: false,
+            ..default()
+        }),
+        Light2dPlugin,
+        HolidayCardPlugin::default(),
+    ))
+    .add_systems(Update, handle_thumbnail_capture);
+
+    app.run()
+}
+
+// Default plugins with the card's pixel art sampling, spatial audio scale and window.
+fn default_plugins(window: Window) -> PluginGroupBuilder {
+    DefaultPlugins
+        .set(ImagePlugin::default_nearest())
+        .set(AudioPlugin {
+            default_spatial_scale: SpatialScale::new_2d(AUDIO_SCALE),
+            ..default()
+        })
+        .set(WindowPlugin {
+            primary_window: Some(window),
+            ..default()
+        })
+}
+
+// Toggle the frame rate cap, and while it's on sleep off whatever is left of the frame's time.
+#[cfg(not(target_arch = "wasm32"))]
+fn handle_frame_limit(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut limit: ResMut<FrameLimit>,
+    mut frame_start: Local<Option<Instant>>,
+) {
+    if keyboard.just_pressed(limit.toggle_key) {
+        limit.enabled = !limit.enabled;
+    }
+
+    if limit.enabled
+        && limit.fps > 0
+        && let Some(start) = *frame_start
+    {
+        let frame_time = Duration::from_secs_f64(1.0 / f64::from(limit.fps));
+        if let Some(remaining) = frame_time.checked_sub(start.elapsed()) {
+            std::thread::sleep(remaining);
+        }
+    }
+
+    *frame_start = Some(Instant::now());
+}
+
+// Capture the export render once the scene has run for the thumbnail frames, exiting once it's saved.
+#[cfg(not(target_arch = "wasm32"))]
+fn handle_thumbnail_capture(
+    mut commands: Commands,
+    capture: Res<ThumbnailCapture>,
+    export_target: Option<Res<ExportTarget>>,
+    mut exit: MessageWriter<AppExit>,
+    mut frames: Local<u32>,
+) {
+    *frames += 1;
+    if *frames != THUMBNAIL_FRAMES {
+        return;
+    }
+
+    let Some(export_target) = export_target else {
+        error!("thumbnail export target is missing, nothing to capture");
+        exit.write(AppExit::error());
+        return;
+    };
+    commands
+        .spawn(Screenshot::image(export_target.0.clone()))
+        .observe(save_to_disk(capture.path.clone()))
+        .observe(|_: On<ScreenshotCaptured>, mut exit: MessageWriter<AppExit>| {
+            exit.write(AppExit::Success);
+        });
+}
+
+// Desktop window settings.
+#[cfg(not(target_arch = "wasm32"))]
+fn primary_window() -> Window {
+    Window::default()
+}
+
+// Browser canvas settings, the canvas fills its parent and default browser input handling is blocked so arrow keys and
+// touches don't scroll the page.
+// NOTE: web/index.html sizes the canvas's parent to keep the card's aspect ratio.
+#[cfg(target_arch = "wasm32")]
+fn primary_window() -> Window {
+    Window {
+        canvas: Some("#holiday-card".to_string()),
+        fit_canvas_to_parent: true,
+        prevent_default_event_handling: true,
+        ..default()
+    }
+}
@@ -0,0 +1,141 @@
+use bevy::input::touch::Touches;
+use bevy::prelude::*;
+
+use crate::{
+    fireplace, house_lights,
+    input::{InputEvent, InputTarget, Intent},
+    interaction::Interactable,
+    replay, stereo, tree,
+};
+
+// A scripted walk to the prop with the interactable id, optionally interacting on arrival, then waiting before the
+// next step. Steps for props missing from the scene are skipped.
+#[derive(Clone, Debug)]
+pub struct AttractStep {
+    pub id: String,
+    pub action: bool,
+    pub wait: f32,
+}
+
+// Attract mode configuration, the script loops until real input arrives.
+#[derive(Clone, Resource)]
+pub struct AttractConfig {
+    pub idle_seconds: f32,
+    pub script: Vec<AttractStep>,
+}
+
+impl Default for AttractConfig {
+    // Visit the tree, stereo, fireplace, and light switch in turn, toggling each one.
+    fn default() -> Self {
+        Self {
+            idle_seconds: 30.0,
+            script: vec![
+                AttractStep {
+                    id: tree::INTERACTABLE_ID.to_string(),
+                    action: true,
+                    wait: 10.0,
+                },
+                AttractStep {
+                    id: stereo::INTERACTABLE_ID.to_string(),
+                    action: true,
+                    wait: 10.0,
+                },
+                AttractStep {
+                    id: fireplace::INTERACTABLE_ID.to_string(),
+                    action: true,
+                    wait: 10.0,
+                },
+                AttractStep {
+                    id: house_lights::INTERACTABLE_ID.to_string(),
+                    action: true,
+                    wait: 10.0,
+                },
+            ],
+        }
+    }
+}
+
+// Attract mode progress, active while the script is driving the man.
+#[derive(Resource)]
+pub struct Attract {
+    pub active: bool,
+    idle_timer: Timer,
+    step: usize,
+    step_timer: Timer,
+}
+
+// Add the attract mode systems, the script waits while a replay is playing back.
+pub fn add_systems(app: &mut App) {
+    app.init_resource::<AttractConfig>()
+        .add_systems(Startup, init)
+        .add_systems(Update, handle_attract.run_if(not(replay::is_playing)));
+}
+
+// Cancel on real input, otherwise start the script once idle long enough and advance it over time.
+fn handle_attract(
+    time: Res<Time>,
+    config: Res<AttractConfig>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    mut attract: ResMut<Attract>,
+    props: Query<(&Interactable, &GlobalTransform)>,
+    mut input_events: MessageWriter<InputEvent>,
+) {
+    // Any real input, including a key, button, or touch that's still held, stops the script and restarts the idle
+    // countdown.
+    if keyboard.get_pressed().next().is_some()
+        || mouse.get_pressed().next().is_some()
+        || touches.iter().next().is_some()
+    {
+        attract.active = false;
+        attract.idle_timer.reset();
+        attract.step = 0;
+        return;
+    }
+
+    if config.script.is_empty() {
+        return;
+    }
+
+    if attract.active {
+        attract.step_timer.tick(time.delta());
+        if !attract.step_timer.just_finished() {
+            return;
+        }
+        attract.step = (attract.step + 1) % config.script.len();
+    } else {
+        attract.idle_timer.tick(time.delta());
+        if !attract.idle_timer.just_finished() {
+            return;
+        }
+        attract.active = true;
+        attract.step = 0;
+    }
+
+    // Walk to the next prop that's in the scene, its position is read from the prop so the script follows the layout.
+    for _ in 0..config.script.len() {
+        let step = &config.script[attract.step];
+        if let Some((interactable, transform)) = props.iter().find(|(interactable, _)| interactable.id == step.id) {
+            input_events.write(InputEvent {
+                intent: Intent::Navigate(InputTarget {
+                    x: interactable.center(transform).x,
+                    action: step.action,
+                }),
+            });
+            attract.step_timer = Timer::from_seconds(step.wait, TimerMode::Once);
+            return;
+        }
+        attract.step = (attract.step + 1) % config.script.len();
+    }
+}
+
+// Attract mode initialization.
+fn init(mut commands: Commands, config: Res<AttractConfig>) {
+    commands.insert_resource(Attract {
+        active: false,
+        idle_timer: Timer::from_seconds(config.idle_seconds, TimerMode::Once),
+        step: 0,
+        step_timer: Timer::default(),
+    });
+}
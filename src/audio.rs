@@ -0,0 +1,356 @@
+use bevy::{audio::Volume, input::touch::Touches, prelude::*};
+
+use crate::{house::SceneRegions, interaction::State};
+
+// Linear volume of each sound source, the whole mix can be balanced here.
+#[derive(Clone, Resource)]
+pub struct AudioConfig {
+    pub fireplace: f32,
+    pub footsteps: f32,
+    pub light_switch: f32,
+    pub stereo: f32,
+    pub one_shot_cleanup: OneShotCleanup,
+}
+
+impl Default for AudioConfig {
+    fn default() -> Self {
+        Self {
+            fireplace: 0.75,
+            footsteps: 0.85,
+            light_switch: 0.40,
+            stereo: 0.9,
+            one_shot_cleanup: OneShotCleanup::default(),
+        }
+    }
+}
+
+// Muffles spatial sounds heard from the other side of the house wall.
+#[derive(Clone, Resource)]
+pub struct AudioOcclusion {
+    pub factor: f32,
+}
+
+impl Default for AudioOcclusion {
+    fn default() -> Self {
+        Self { factor: 0.35 }
+    }
+}
+
+// Optional non-spatial music that plays the whole time independent of the stereo, track is an asset path and the
+// mute key toggles it. Like every sound it's scaled by the GlobalVolume.
+#[derive(Clone, Resource)]
+pub struct BackgroundMusic {
+    pub track: Option<String>,
+    pub volume: f32,
+    pub mute_key: KeyCode,
+}
+
+impl Default for BackgroundMusic {
+    fn default() -> Self {
+        Self {
+            track: None,
+            volume: 0.5,
+            mute_key: KeyCode::KeyM,
+        }
+    }
+}
+
+// Whether looping sounds may start, browsers block audio until the first user gesture so web builds start locked.
+#[derive(Resource)]
+pub struct AudioUnlocked(pub bool);
+
+impl Default for AudioUnlocked {
+    fn default() -> Self {
+        Self(!cfg!(target_arch = "wasm32"))
+    }
+}
+
+// How one-shot sounds are cleaned up once they finish, either despawning the entity or only removing the audio
+// components and leaving the rest to the finished audio cleanup.
+// NOTE: web builds default to removing since despawning from the audio system doesn't always happen there.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OneShotCleanup {
+    Despawn,
+    Remove,
+}
+
+impl Default for OneShotCleanup {
+    fn default() -> Self {
+        if cfg!(target_arch = "wasm32") {
+            Self::Remove
+        } else {
+            Self::Despawn
+        }
+    }
+}
+
+#[derive(Component)]
+struct Music;
+
+// Occlusion factor applied to a spatial sink while it's heard through the house wall, it's divided back out once the
+// listener and the emitter are on the same side again so other volume changes in the meantime are kept.
+#[derive(Component)]
+pub struct Occluded(pub f32);
+
+// Time an audio entity was spawned, used to clean up finished sounds that didn't despawn themselves.
+#[derive(Component)]
+struct SpawnedAt(f32);
+
+// Seconds a finished audio entity may linger before it's despawned.
+const FINISHED_AUDIO_TIMEOUT: f32 = 5.0;
+
+// Add the audio systems.
+pub fn add_systems(app: &mut App) {
+    app.init_resource::<AudioConfig>()
+        .init_resource::<AudioOcclusion>()
+        .init_resource::<BackgroundMusic>()
+        .init_resource::<AudioUnlocked>()
+        .add_systems(
+            Update,
+            (
+                handle_audio_unlock,
+                handle_finished_audio,
+                handle_music,
+                handle_music_mute,
+                handle_new_audio,
+                handle_occlusion,
+            ),
+        )
+        .add_systems(Last, handle_shutdown);
+}
+
+// Unlock audio on the first key press, click, or touch.
+fn handle_audio_unlock(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    mut unlocked: ResMut<AudioUnlocked>,
+) {
+    if !unlocked.0
+        && (keyboard.get_just_pressed().next().is_some()
+            || mouse.get_just_pressed().next().is_some()
+            || touches.any_just_pressed())
+    {
+        unlocked.0 = true;
+    }
+}
+
+// Despawn audio entities that have finished playing once they're old enough, a safety net for the DESPAWN setting
+// that also clears out the entities left behind by the REMOVE setting.
+fn handle_finished_audio(
+    mut commands: Commands,
+    time: Res<Time>,
+    query: Query<(Entity, &SpawnedAt, Option<&AudioSink>, Has<AudioPlayer>)>,
+) {
+    let mut despawned = 0;
+    for (entity, spawned_at, sink, has_player) in &query {
+        let expired = time.elapsed_secs() - spawned_at.0 > FINISHED_AUDIO_TIMEOUT;
+        let finished = !has_player || sink.is_some_and(AudioSinkPlayback::empty);
+        if expired && finished {
+            commands.entity(entity).despawn();
+            despawned += 1;
+        }
+    }
+
+    if despawned > 0 {
+        debug!(
+            "despawned {despawned} finished audio entities, {} still live",
+            query.iter().count() - despawned
+        );
+    }
+}
+
+// Start the background music once audio is unlocked, if a track is configured.
+fn handle_music(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    music: Res<BackgroundMusic>,
+    unlocked: Res<AudioUnlocked>,
+    query: Query<(), With<Music>>,
+) {
+    if !unlocked.0 || !query.is_empty() {
+        return;
+    }
+    let Some(track) = &music.track else {
+        return;
+    };
+
+    commands.spawn((
+        Music,
+        AudioPlayer::new(asset_server.load(track.clone())),
+        PlaybackSettings::LOOP.with_volume(Volume::Linear(music.volume)),
+    ));
+}
+
+// Toggle the background music on the mute key.
+fn handle_music_mute(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    music: Res<BackgroundMusic>,
+    mut query: Query<&mut AudioSink, With<Music>>,
+) {
+    if !keyboard.just_pressed(music.mute_key) {
+        return;
+    }
+
+    for mut sink in &mut query {
+        sink.toggle_mute();
+    }
+}
+
+// Scale spatial sounds down as the listener and the emitter end up on opposite sides of the house wall and back up as
+// they meet again, only the crossings touch the sink so volume set elsewhere is scaled rather than replaced.
+fn handle_occlusion(
+    mut commands: Commands,
+    occlusion: Res<AudioOcclusion>,
+    regions: Res<SceneRegions>,
+    listener: Query<&GlobalTransform, With<SpatialListener>>,
+    mut emitters: Query<(
+        Entity,
+        &GlobalTransform,
+        &PlaybackSettings,
+        &mut SpatialAudioSink,
+        Option<&Occluded>,
+    )>,
+) {
+    let Ok(listener_transform) = listener.single() else {
+        return;
+    };
+    let listener_region = regions.region_at(listener_transform.translation().x);
+
+    for (entity, transform, settings, mut sink, occluded) in &mut emitters {
+        let separated = regions.region_at(transform.translation().x) != listener_region;
+
+        match (separated, occluded) {
+            (true, None) => {
+                let volume = sink.volume().to_linear() * occlusion.factor;
+                sink.set_volume(Volume::Linear(volume));
+                commands.entity(entity).insert(Occluded(occlusion.factor));
+            }
+            (false, Some(Occluded(factor))) => {
+                // A silencing factor can't be divided back out, fall back to the playback settings.
+                let volume = if *factor > 0.0 {
+                    Volume::Linear(sink.volume().to_linear() / factor)
+                } else {
+                    settings.volume
+                };
+                sink.set_volume(volume);
+                commands.entity(entity).remove::<Occluded>();
+            }
+            _ => {}
+        }
+    }
+}
+
+// Pause every sink once the app is asked to exit so looping sounds stop cleanly instead of clicking as they're torn
+// down. There's no exit schedule so this runs in Last, holding the exit back for a frame so the pause reaches the audio
+// thread before the sinks are dropped, then sending it again.
+fn handle_shutdown(
+    mut exits: ResMut<Messages<AppExit>>,
+    mut pending: Local<Option<AppExit>>,
+    sinks: Query<&AudioSink>,
+    spatial_sinks: Query<&SpatialAudioSink>,
+) {
+    if let Some(exit) = pending.take() {
+        exits.write(exit);
+        return;
+    }
+
+    let mut requested = exits.drain();
+    let Some(first) = requested.next() else {
+        return;
+    };
+    *pending = Some(requested.find(AppExit::is_error).unwrap_or(first));
+
+    for sink in &sinks {
+        sink.pause();
+    }
+    for sink in &spatial_sinks {
+        sink.pause();
+    }
+}
+
+// Record when each audio entity was spawned.
+fn handle_new_audio(mut commands: Commands, time: Res<Time>, query: Query<Entity, Added<AudioPlayer>>) {
+    for entity in &query {
+        commands.entity(entity).insert(SpawnedAt(time.elapsed_secs()));
+    }
+}
+
+// Change a looping prop's sink needs to match the prop's state, true to play and false to pause.
+pub fn sound_change(state: State, paused: bool, unlocked: bool) -> Option<bool> {
+    match (state, paused) {
+        // Start the sound effect if it isn't already running, once audio is allowed to play.
+        (State::On, true) if unlocked => Some(true),
+
+        // Pause the sound effect if it's still running.
+        (State::Off, false) => Some(false),
+
+        _ => None,
+    }
+}
+
+// Spawn a sound that plays once and is cleaned up afterwards, all one-shot sounds go through here.
+pub fn play_one_shot(commands: &mut Commands, audio_config: &AudioConfig, source: Handle<AudioSource>, volume: f32) {
+    let settings = match audio_config.one_shot_cleanup {
+        OneShotCleanup::Despawn => PlaybackSettings::DESPAWN,
+        OneShotCleanup::Remove => PlaybackSettings::REMOVE,
+    };
+
+    commands.spawn((AudioPlayer::new(source), settings.with_volume(Volume::Linear(volume))));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    #[test]
+    fn finished_audio_is_despawned_after_the_timeout() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)))
+            .add_systems(Update, handle_finished_audio);
+        app.update();
+
+        // Playback removes the player from finished one-shots with the REMOVE setting, the rest are still waiting on
+        // their sinks.
+        let spawn_sounds = |app: &mut App| -> Vec<Entity> {
+            (0..100)
+                .map(|_| {
+                    app.world_mut()
+                        .spawn((AudioPlayer::new(Handle::default()), SpawnedAt(0.0)))
+                        .id()
+                })
+                .collect()
+        };
+        let finished = spawn_sounds(&mut app);
+        let playing = spawn_sounds(&mut app);
+        for &entity in &finished {
+            app.world_mut().entity_mut(entity).remove::<AudioPlayer>();
+        }
+
+        for _ in 0..5 {
+            app.update();
+        }
+        assert!(finished.iter().all(|&entity| app.world().get_entity(entity).is_ok()));
+
+        app.update();
+        assert!(finished.iter().all(|&entity| app.world().get_entity(entity).is_err()));
+        assert!(playing.iter().all(|&entity| app.world().get_entity(entity).is_ok()));
+    }
+
+    #[test]
+    fn exit_is_held_back_a_frame_for_the_sinks_to_pause() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins).add_systems(Last, handle_shutdown);
+        app.update();
+
+        app.world_mut().write_message(AppExit::error());
+        app.update();
+        assert_eq!(app.should_exit(), None);
+
+        app.update();
+        assert_eq!(app.should_exit(), Some(AppExit::error()));
+    }
+}
@@ -1,38 +1,227 @@
-use bevy::prelude::*;
+use bevy::{
+    math::curve::{Curve, EaseFunction},
+    prelude::*,
+};
 use bevy_light_2d::prelude::*;
 
+use crate::{fireplace::Fireplace, flickering_light::FlickeringSpotLight, interaction::State, snow::FirstSnow};
+
 #[derive(Component)]
 struct Background;
 
+#[derive(Component)]
+struct MeltedPatch;
+
+#[derive(Component)]
+struct Moon;
+
+// Moonlight settings, changes are applied to the spotlight live. Clouds slowly dim and brighten the moon.
+#[derive(Clone, Resource)]
+pub struct Moonlight {
+    pub color: Color,
+    pub intensity: f32,
+    pub direction: f32,
+    pub inner_angle: f32,
+    pub outer_angle: f32,
+    pub clouds: bool,
+}
+
+impl Default for Moonlight {
+    fn default() -> Self {
+        Self {
+            color: Color::srgba(1.0, 1.0, 1.0, 1.0),
+            intensity: 0.4,
+            direction: 135.0,
+            inner_angle: 40.0,
+            outer_angle: 60.0,
+            clouds: false,
+        }
+    }
+}
+
+// Melted patch in the ground snow while the fireplace is lit, fading in as it melts and out as it refreezes.
+// NOTE: the fireplace is indoors, so the patch defaults to the exposed snow beside the house wall.
+#[derive(Clone, Resource)]
+pub struct SnowMelt {
+    pub x: f32,
+    pub width: f32,
+    pub color: Color,
+    pub melt_seconds: f32,
+    pub freeze_seconds: f32,
+}
+
+impl Default for SnowMelt {
+    fn default() -> Self {
+        Self {
+            x: -100.0,
+            width: 12.0,
+            color: Color::srgba(0.55, 0.6, 0.75, 0.8),
+            melt_seconds: 60.0,
+            freeze_seconds: 120.0,
+        }
+    }
+}
+
+// How the ground snow builds up, the movement stops once it reaches its height.
+#[derive(Clone, Copy, Resource)]
+pub enum SnowAccumulation {
+    // Rise the given height over a fixed number of seconds, following the easing curve.
+    // NOTE: the snow never sinks back down, so easings that dip below the start hold still until they climb again.
+    Once {
+        seconds: f32,
+        rise: f32,
+        easing: EaseFunction,
+    },
+    // Keep rising at a rate in pixels per second up to a cap, for long running displays.
+    Continuous {
+        rate: f32,
+        max_height: f32,
+    },
+}
+
+impl Default for SnowAccumulation {
+    fn default() -> Self {
+        Self::Once {
+            seconds: 60.0 * 5.0,
+            rise: 15.0,
+            easing: EaseFunction::Linear,
+        }
+    }
+}
+
+// Seconds the ground stays bare before the snow starts building up, counted from the first snow with the intro on.
+#[derive(Clone, Copy, Default, Resource)]
+pub struct SnowRiseDelay(pub f32);
+
+// Ground snow build up, delay counts down the seconds left before it starts rising and elapsed counts the seconds
+// it's been rising since.
 #[derive(Component)]
 struct SnowMovement {
-    timer: Timer,
-    rise: f32,
-    progress: f32,
+    mode: SnowAccumulation,
+    height: f32,
+    delay: f32,
+    elapsed: f32,
 }
 
+// Clouds drifting over the moon, a slow and subtle variation of its intensity.
+const CLOUD_AMPLITUDE: f32 = 0.2;
+const CLOUD_FREQUENCY: f32 = 0.05;
+const CLOUD_SEED: f32 = 42.0;
+
 // Add the animation systems.
 pub fn add_systems(app: &mut App) {
-    app.add_systems(Startup, init).add_systems(Update, handle_snow);
+    app.init_resource::<Moonlight>()
+        .init_resource::<SnowAccumulation>()
+        .init_resource::<SnowMelt>()
+        .init_resource::<SnowRiseDelay>()
+        .add_systems(Startup, init)
+        .add_systems(Update, (handle_moonlight, handle_snow, handle_snow_melt));
+}
+
+// Apply moonlight setting changes to the spotlight, clouds vary the intensity around the setting.
+fn handle_moonlight(
+    mut commands: Commands,
+    moonlight: Res<Moonlight>,
+    mut query: Query<(Entity, &mut SpotLight2d), With<Moon>>,
+) {
+    if !moonlight.is_changed() {
+        return;
+    }
+
+    for (entity, mut light) in &mut query {
+        if moonlight.clouds {
+            commands.entity(entity).insert(FlickeringSpotLight {
+                seed: CLOUD_SEED,
+                base_intensity: moonlight.intensity,
+                intensity_amplitude: CLOUD_AMPLITUDE,
+                intensity_frequency: CLOUD_FREQUENCY,
+                intensity_octaves: 2,
+            });
+        } else {
+            commands.entity(entity).remove::<FlickeringSpotLight>();
+        }
+
+        light.color = moonlight.color;
+        light.intensity = moonlight.intensity;
+        light.direction = moonlight.direction;
+        light.inner_angle = moonlight.inner_angle;
+        light.outer_angle = moonlight.outer_angle;
+    }
 }
 
-// Handle the snow rising over time.
-fn handle_snow(time: Res<Time>, mut commands: Commands, mut query: Query<(Entity, &mut SnowMovement, &mut Transform)>) {
+// Handle the snow rising over time, never past its cap, held until the first snow intro has started and then for the
+// rise delay.
+fn handle_snow(
+    time: Res<Time>,
+    first_snow: Res<FirstSnow>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut SnowMovement, &mut Transform)>,
+) {
+    if !first_snow.started(time.elapsed_secs()) {
+        return;
+    }
+
     for (entity, mut snow, mut transform) in &mut query {
-        snow.timer.tick(time.delta());
+        if snow.delay > 0.0 {
+            snow.delay -= time.delta_secs();
+            continue;
+        }
 
-        let progress = snow.timer.fraction();
-        transform.translation.y += (progress - snow.progress) * snow.rise;
-        snow.progress = progress;
+        snow.elapsed += time.delta_secs();
+        let (step, limit) = match snow.mode {
+            SnowAccumulation::Once { seconds, rise, easing } => {
+                // Ends exactly at the height once the time is up, whatever the easing.
+                let progress = if snow.elapsed >= seconds {
+                    1.0
+                } else {
+                    easing.sample_clamped(snow.elapsed / seconds)
+                };
+                (rise * progress - snow.height, rise)
+            }
+            SnowAccumulation::Continuous { rate, max_height } => (rate * time.delta_secs(), max_height),
+        };
 
-        if snow.timer.just_finished() {
+        let step = step.min(limit - snow.height).max(0.0);
+        transform.translation.y += step;
+        snow.height += step;
+
+        if snow.height >= limit {
             commands.entity(entity).remove::<SnowMovement>();
         }
     }
 }
 
+// Fade the melted patch in while the fireplace is lit and back out once it's off.
+fn handle_snow_melt(
+    time: Res<Time>,
+    melt: Res<SnowMelt>,
+    fireplace_query: Query<&State, With<Fireplace>>,
+    mut query: Query<&mut Sprite, With<MeltedPatch>>,
+) {
+    let lit = fireplace_query.iter().any(|state| *state == State::On);
+    let max_alpha = melt.color.alpha();
+
+    for mut sprite in &mut query {
+        let step = if lit {
+            max_alpha * time.delta_secs() / melt.melt_seconds
+        } else {
+            -max_alpha * time.delta_secs() / melt.freeze_seconds
+        };
+
+        let alpha = (sprite.color.alpha() + step).clamp(0.0, max_alpha);
+        sprite.color.set_alpha(alpha);
+    }
+}
+
 // Background initialization.
-fn init(mut commands: Commands, asset_server: Res<AssetServer>) {
+fn init(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    accumulation: Res<SnowAccumulation>,
+    melt: Res<SnowMelt>,
+    moonlight: Res<Moonlight>,
+    rise_delay: Res<SnowRiseDelay>,
+) {
     // Background.
     let background = asset_server.load("background/background.png");
     commands.spawn((
@@ -46,33 +235,131 @@ fn init(mut commands: Commands, asset_server: Res<AssetServer>) {
 
     // Snow on the ground, z = 1.5 to be in front of the falling snow.
     let snow = asset_server.load("background/snow.png");
-    commands.spawn((
-        Sprite {
-            image: snow,
-            ..default()
-        },
-        Transform::from_xyz(0.0, -75.0, 1.5),
-        Background,
-        SnowMovement {
-            timer: Timer::from_seconds(60.0 * 5.0, TimerMode::Once),
-            rise: 15.0,
-            progress: 0.0,
-        },
-    ));
+    let ground_snow = commands
+        .spawn((
+            Sprite {
+                image: snow,
+                ..default()
+            },
+            Transform::from_xyz(0.0, -75.0, 1.5),
+            Background,
+            SnowMovement {
+                mode: *accumulation,
+                height: 0.0,
+                delay: rise_delay.0,
+                elapsed: 0.0,
+            },
+        ))
+        .id();
+
+    // Melted patch starts frozen over, as a child it rises with the ground snow.
+    let patch = commands
+        .spawn((
+            Sprite {
+                color: melt.color.with_alpha(0.0),
+                custom_size: Some(Vec2::new(melt.width, 3.0)),
+                ..default()
+            },
+            Transform::from_xyz(melt.x, 4.0, 0.1),
+            MeltedPatch,
+        ))
+        .id();
+    commands.entity(ground_snow).add_child(patch);
 
     // Moonlight.
     commands.spawn((
         SpotLight2d {
-            color: Color::srgba(1.0, 1.0, 1.0, 1.0),
-            intensity: 0.4,
+            color: moonlight.color,
+            intensity: moonlight.intensity,
             radius: 200.0,
-            direction: 135.0,
-            inner_angle: 40.0,
-            outer_angle: 60.0,
+            direction: moonlight.direction,
+            inner_angle: moonlight.inner_angle,
+            outer_angle: moonlight.outer_angle,
             source_width: 1.0,
             cast_shadows: true,
             ..default()
         },
         Transform::from_xyz(-160.0, 140.0, 2.0),
+        Moon,
     ));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    #[test]
+    fn snow_stays_put_until_the_rise_delay_is_up() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)))
+            .init_resource::<FirstSnow>()
+            .add_systems(Update, handle_snow);
+        let snow = app
+            .world_mut()
+            .spawn((
+                SnowMovement {
+                    mode: SnowAccumulation::Continuous {
+                        rate: 10.0,
+                        max_height: 100.0,
+                    },
+                    height: 0.0,
+                    delay: 1.0,
+                    elapsed: 0.0,
+                },
+                Transform::default(),
+            ))
+            .id();
+
+        // Still within the second's delay.
+        for _ in 0..8 {
+            app.update();
+        }
+        assert_eq!(app.world().get::<Transform>(snow).unwrap().translation.y, 0.0);
+
+        for _ in 0..20 {
+            app.update();
+        }
+        assert!(app.world().get::<Transform>(snow).unwrap().translation.y > 0.0);
+    }
+
+    #[test]
+    fn snow_rise_follows_the_easing() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)))
+            .init_resource::<FirstSnow>()
+            .add_systems(Update, handle_snow);
+        let snow = app
+            .world_mut()
+            .spawn((
+                SnowMovement {
+                    mode: SnowAccumulation::Once {
+                        seconds: 2.0,
+                        rise: 16.0,
+                        easing: EaseFunction::QuadraticIn,
+                    },
+                    height: 0.0,
+                    delay: 0.0,
+                    elapsed: 0.0,
+                },
+                Transform::default(),
+            ))
+            .id();
+
+        // The first update only starts the clock, then halfway through a quadratic ease in is a quarter of the way up.
+        for _ in 0..11 {
+            app.update();
+        }
+        let y = app.world().get::<Transform>(snow).unwrap().translation.y;
+        assert!((y - 4.0).abs() < 1e-3);
+
+        for _ in 0..11 {
+            app.update();
+        }
+        assert_eq!(app.world().get::<Transform>(snow).unwrap().translation.y, 16.0);
+        assert!(app.world().get::<SnowMovement>(snow).is_none());
+    }
+}
@@ -1,40 +1,645 @@
-use bevy::{camera::ScalingMode, prelude::*};
+use bevy::window::PrimaryWindow;
+use bevy::{
+    asset::RenderAssetUsages,
+    camera::{RenderTarget, ScalingMode},
+    image::ImageSampler,
+    input::mouse::AccumulatedMouseScroll,
+    post_process::bloom::Bloom,
+    prelude::*,
+    render::{
+        render_resource::{Extent3d, TextureDimension, TextureFormat, TextureUsages},
+        view::screenshot::{Screenshot, save_to_disk},
+    },
+};
 use bevy_light_2d::prelude::*;
 
+use crate::theman::TheMan;
+
 const WINDOW_HEIGHT: f32 = 150.0;
 const WINDOW_WIDTH: f32 = 300.0;
 
 const AMBIENT_BRIGHTNESS: f32 = 0.035;
 
+const EXPORT_PATH: &str = "holiday_card.png";
+
+// Size of the generated vignette texture, it's stretched over the window so only the falloff shape matters, and the
+// distance from the centre where the darkening starts as a fraction of the way to the corners.
+const VIGNETTE_WIDTH: u16 = 128;
+const VIGNETTE_HEIGHT: u16 = 64;
+const VIGNETTE_INNER: f32 = 0.45;
+
+// Change in zoom scale per key press or scroll notch.
+const ZOOM_STEP: f32 = 0.1;
+
+// The on-screen camera.
+#[derive(Component)]
+pub struct MainCamera;
+
+// Offscreen rendering of the scene at a fixed resolution for high resolution captures, F12 saves a capture.
+#[derive(Clone, Resource)]
+pub struct ExportRender {
+    pub enabled: bool,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Default for ExportRender {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            width: 1920,
+            height: 960,
+        }
+    }
+}
+
+// Image the export camera renders into.
+#[derive(Clone, Resource)]
+pub struct ExportTarget(pub Handle<Image>);
+
+// Subtle bloom on the on-screen camera so the lights bleed gently, toggled with the G key.
+#[derive(Clone, Resource)]
+pub struct Glow {
+    pub enabled: bool,
+    pub intensity: f32,
+}
+
+impl Default for Glow {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            intensity: 0.08,
+        }
+    }
+}
+
+// Soft darkening around the window edges, toggled with the V key. Strength is the opacity at the corners.
+#[derive(Clone, Resource)]
+pub struct Vignette {
+    pub enabled: bool,
+    pub strength: f32,
+}
+
+impl Default for Vignette {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            strength: 0.45,
+        }
+    }
+}
+
+#[derive(Component)]
+struct VignetteOverlay;
+
+// Brightness boost for low-contrast vision, raises the ambient light and scales flickering light intensities.
+#[derive(Clone, Resource)]
+pub struct NightVision {
+    pub enabled: bool,
+    pub ambient_brightness: f32,
+    pub intensity_boost: f32,
+}
+
+impl Default for NightVision {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            ambient_brightness: 0.2,
+            intensity_boost: 1.5,
+        }
+    }
+}
+
+impl NightVision {
+    // Ambient brightness for the current setting.
+    pub fn ambient(&self) -> f32 {
+        if self.enabled {
+            self.ambient_brightness
+        } else {
+            AMBIENT_BRIGHTNESS
+        }
+    }
+
+    // Multiplier applied to light intensities for the current setting.
+    pub fn intensity_multiplier(&self) -> f32 {
+        if self.enabled { self.intensity_boost } else { 1.0 }
+    }
+}
+
+// Light shadow cost preset, lower settings drop shadows from smaller lights and trim light radii.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Resource)]
+pub enum ShadowQuality {
+    Low,
+    #[default]
+    Medium,
+    High,
+}
+
+impl ShadowQuality {
+    // Smallest light radius that still casts shadows.
+    fn min_shadow_radius(self) -> f32 {
+        match self {
+            Self::Low => 100.0,
+            Self::Medium => 40.0,
+            Self::High => 0.0,
+        }
+    }
+
+    // Multiplier applied to the authored light radii.
+    fn radius_scale(self) -> f32 {
+        match self {
+            Self::Low => 0.85,
+            Self::Medium | Self::High => 1.0,
+        }
+    }
+}
+
+// Multiplier on every point light radius on top of the shadow quality, for dialing the lighting down on small screens.
+#[derive(Clone, Copy, Resource)]
+pub struct LightRadiusScale(pub f32);
+
+impl Default for LightRadiusScale {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+// Authored shadow settings of a light, the shadow quality is applied relative to these.
+#[derive(Component)]
+struct ShadowDefaults {
+    cast_shadows: bool,
+    radius: f32,
+}
+
+// Language of the help text, cycled with the L key.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Resource)]
+pub enum Language {
+    #[default]
+    English,
+    Spanish,
+    French,
+}
+
+impl Language {
+    // The language after this one, wrapping back to English.
+    fn next(self) -> Self {
+        match self {
+            Self::English => Self::Spanish,
+            Self::Spanish => Self::French,
+            Self::French => Self::English,
+        }
+    }
+
+    // Help text lines, top to bottom.
+    fn help_lines(self) -> [&'static str; 2] {
+        match self {
+            Self::English => ["move: left/right - interact: up", "or click to move and interact"],
+            Self::Spanish => [
+                "mover: izquierda/derecha - interactuar: arriba",
+                "o haz clic para moverte e interactuar",
+            ],
+            Self::French => [
+                "bouger : gauche/droite - interagir : haut",
+                "ou cliquez pour bouger et interagir",
+            ],
+        }
+    }
+}
+
+// Help text size at the reference window height, the whole UI scales with the window height so the text keeps its
+// share of the window and the positions keep their place.
+#[derive(Clone, Resource)]
+pub struct HelpTextSize {
+    pub font_size: f32,
+    pub reference_height: f32,
+}
+
+impl Default for HelpTextSize {
+    fn default() -> Self {
+        Self {
+            font_size: 20.0,
+            reference_height: 720.0,
+        }
+    }
+}
+
+// Smallest UI scale, so tiny windows keep readable text.
+const MIN_UI_SCALE: f32 = 0.5;
+
+// A line of the help text, by index into the language's help lines.
+#[derive(Component)]
+struct HelpText(usize);
+
+// Zoom of the on-screen camera as a fraction of the full scene, min_scale is the closest zoom allowed.
+#[derive(Clone, Resource)]
+pub struct CameraZoom {
+    pub scale: f32,
+    pub min_scale: f32,
+}
+
+impl Default for CameraZoom {
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            min_scale: 0.4,
+        }
+    }
+}
+
+// Camera presets, the fixed framing shows the whole card while the follow modes track the man.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Resource)]
+pub enum CameraMode {
+    #[default]
+    Fixed,
+    Follow,
+    FollowZoom,
+}
+
+impl CameraMode {
+    // The preset after this one, wrapping back to fixed.
+    fn next(self) -> Self {
+        match self {
+            Self::Fixed => Self::Follow,
+            Self::Follow => Self::FollowZoom,
+            Self::FollowZoom => Self::Fixed,
+        }
+    }
+}
+
+// Key that cycles the camera presets and the zoom scales used by the follow and close-up presets. Following needs a
+// scale below 1.0, the full scene framing leaves the camera no room to move.
+#[derive(Clone, Resource)]
+pub struct CameraModeControls {
+    pub cycle_key: KeyCode,
+    pub follow_scale: f32,
+    pub close_up_scale: f32,
+}
+
+impl Default for CameraModeControls {
+    fn default() -> Self {
+        Self {
+            cycle_key: KeyCode::KeyC,
+            follow_scale: 0.8,
+            close_up_scale: 0.5,
+        }
+    }
+}
+
 // Add the camera systems.
 pub fn add_systems(app: &mut App) {
-    app.add_systems(Startup, init);
+    app.init_resource::<CameraMode>()
+        .init_resource::<CameraModeControls>()
+        .init_resource::<CameraZoom>()
+        .init_resource::<ExportRender>()
+        .init_resource::<Glow>()
+        .init_resource::<HelpTextSize>()
+        .init_resource::<Language>()
+        .init_resource::<LightRadiusScale>()
+        .init_resource::<NightVision>()
+        .init_resource::<ShadowQuality>()
+        .init_resource::<Vignette>()
+        .add_systems(Startup, init)
+        .add_systems(
+            Update,
+            (
+                handle_camera_follow.before(handle_zoom),
+                handle_camera_mode.before(handle_camera_follow),
+                handle_export_capture,
+                handle_glow,
+                handle_language,
+                handle_night_vision,
+                handle_shadow_quality,
+                handle_ui_scale,
+                handle_vignette,
+                handle_zoom,
+            ),
+        );
 }
 
-// Camera initialization.
-fn init(mut commands: Commands) {
-    // Create the camera projection.
-    let mut ortho = OrthographicProjection::default_2d();
-    ortho.scaling_mode = ScalingMode::Fixed {
-        width: WINDOW_WIDTH,
-        height: WINDOW_HEIGHT,
+// Keep the on-screen camera centred on the man in the follow presets.
+fn handle_camera_follow(
+    mode: Res<CameraMode>,
+    man_query: Query<&GlobalTransform, With<TheMan>>,
+    mut camera_query: Query<&mut Transform, With<MainCamera>>,
+) {
+    if *mode == CameraMode::Fixed {
+        return;
+    }
+
+    let Ok(man_transform) = man_query.single() else {
+        return;
+    };
+
+    for mut transform in &mut camera_query {
+        transform.translation.x = man_transform.translation().x;
+        transform.translation.y = man_transform.translation().y;
+    }
+}
+
+// Cycle the camera presets with the configured key, the fixed preset recentres at the full scene framing.
+fn handle_camera_mode(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    controls: Res<CameraModeControls>,
+    mut mode: ResMut<CameraMode>,
+    mut zoom: ResMut<CameraZoom>,
+    mut camera_query: Query<&mut Transform, With<MainCamera>>,
+) {
+    if !keyboard.just_pressed(controls.cycle_key) {
+        return;
+    }
+
+    *mode = mode.next();
+    match *mode {
+        CameraMode::Fixed => {
+            zoom.scale = 1.0;
+            for mut transform in &mut camera_query {
+                transform.translation.x = 0.0;
+                transform.translation.y = 0.0;
+            }
+        }
+
+        CameraMode::Follow => {
+            zoom.scale = controls.follow_scale;
+        }
+
+        CameraMode::FollowZoom => {
+            zoom.scale = controls.close_up_scale;
+        }
+    }
+}
+
+// Save the export render to disk with the F12 key.
+fn handle_export_capture(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    export_target: Option<Res<ExportTarget>>,
+) {
+    if keyboard.just_pressed(KeyCode::F12)
+        && let Some(export_target) = export_target
+    {
+        commands
+            .spawn(Screenshot::image(export_target.0.clone()))
+            .observe(save_to_disk(EXPORT_PATH));
+    }
+}
+
+// Toggle the glow with the G key and add or remove bloom on the on-screen camera.
+fn handle_glow(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut glow: ResMut<Glow>,
+    query: Query<Entity, With<MainCamera>>,
+) {
+    if keyboard.just_pressed(KeyCode::KeyG) {
+        glow.enabled = !glow.enabled;
+    }
+
+    if !glow.is_changed() {
+        return;
+    }
+
+    for entity in &query {
+        if glow.enabled {
+            commands.entity(entity).insert(Bloom {
+                intensity: glow.intensity,
+                ..Bloom::NATURAL
+            });
+        } else {
+            commands.entity(entity).remove::<Bloom>();
+        }
+    }
+}
+
+// Cycle the help text language with the L key and update the help text.
+fn handle_language(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut language: ResMut<Language>,
+    mut query: Query<(&HelpText, &mut Text)>,
+) {
+    if keyboard.just_pressed(KeyCode::KeyL) {
+        *language = language.next();
+    }
+
+    if !language.is_changed() {
+        return;
+    }
+
+    let lines = language.help_lines();
+    for (help_text, mut text) in &mut query {
+        if let Some(line) = lines.get(help_text.0) {
+            text.0 = (*line).to_string();
+        }
+    }
+}
+
+// Toggle night vision with the N key and apply it to the ambient light.
+fn handle_night_vision(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut night_vision: ResMut<NightVision>,
+    mut query: Query<&mut Light2d>,
+) {
+    if keyboard.just_pressed(KeyCode::KeyN) {
+        night_vision.enabled = !night_vision.enabled;
+    }
+
+    if night_vision.is_changed() {
+        for mut light in &mut query {
+            light.ambient_light.brightness = night_vision.ambient();
+        }
+    }
+}
+
+// Apply the shadow quality and radius scale to new lights, and to every light when either setting changes.
+fn handle_shadow_quality(
+    mut commands: Commands,
+    quality: Res<ShadowQuality>,
+    radius_scale: Res<LightRadiusScale>,
+    mut new_lights: Query<(Entity, &mut PointLight2d), Without<ShadowDefaults>>,
+    mut lights: Query<(&ShadowDefaults, &mut PointLight2d)>,
+) {
+    for (entity, mut light) in &mut new_lights {
+        let defaults = ShadowDefaults {
+            cast_shadows: light.cast_shadows,
+            radius: light.radius,
+        };
+        apply_shadow_quality(*quality, *radius_scale, &defaults, &mut light);
+        commands.entity(entity).insert(defaults);
+    }
+
+    if quality.is_changed() || radius_scale.is_changed() {
+        for (defaults, mut light) in &mut lights {
+            apply_shadow_quality(*quality, *radius_scale, defaults, &mut light);
+        }
+    }
+}
+
+// Scale the UI with the window height so the text keeps the same share of the window at any size.
+// NOTE: the logical height is used since Bevy UI already applies the window's scale factor on top of UiScale, reading
+// it here as well would count it twice.
+fn handle_ui_scale(
+    text_size: Res<HelpTextSize>,
+    mut ui_scale: ResMut<UiScale>,
+    windows: Query<&Window, (With<PrimaryWindow>, Changed<Window>)>,
+) {
+    let Ok(window) = windows.single() else {
+        return;
+    };
+
+    let scale = (window.height() / text_size.reference_height.max(1.0)).max(MIN_UI_SCALE);
+    if ui_scale.0 != scale {
+        ui_scale.0 = scale;
+    }
+}
+
+// Toggle the vignette with the V key and apply its strength.
+fn handle_vignette(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut vignette: ResMut<Vignette>,
+    mut query: Query<(&mut ImageNode, &mut Visibility), With<VignetteOverlay>>,
+) {
+    if keyboard.just_pressed(KeyCode::KeyV) {
+        vignette.enabled = !vignette.enabled;
+    }
+
+    if !vignette.is_changed() {
+        return;
+    }
+
+    for (mut image, mut visibility) in &mut query {
+        image.color = Color::WHITE.with_alpha(vignette.strength);
+        *visibility = if vignette.enabled {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
+// Zoom with the plus and minus keys or the scroll wheel, keeping the view inside the background.
+fn handle_zoom(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse_scroll: Res<AccumulatedMouseScroll>,
+    mut zoom: ResMut<CameraZoom>,
+    mut query: Query<(&mut Projection, &mut Transform), With<MainCamera>>,
+) {
+    // signum is 1.0 for a zero delta, so only a real scroll counts as a step.
+    let mut steps = if mouse_scroll.delta.y == 0.0 {
+        0.0
+    } else {
+        mouse_scroll.delta.y.signum()
     };
-    let projection = Projection::Orthographic(ortho);
+    if keyboard.just_pressed(KeyCode::Equal) {
+        steps += 1.0;
+    }
+    if keyboard.just_pressed(KeyCode::Minus) {
+        steps -= 1.0;
+    }
+    if steps != 0.0 {
+        zoom.scale = steps.mul_add(-ZOOM_STEP, zoom.scale);
+    }
+    zoom.scale = zoom.scale.clamp(zoom.min_scale.min(1.0), 1.0);
 
+    for (mut projection, mut transform) in &mut query {
+        if let Projection::Orthographic(ortho) = projection.as_mut() {
+            ortho.scale = zoom.scale;
+        }
+
+        // Never show past the edges of the scene.
+        let max_x = (WINDOW_WIDTH / 2.0) * (1.0 - zoom.scale);
+        let max_y = (WINDOW_HEIGHT / 2.0) * (1.0 - zoom.scale);
+        transform.translation.x = transform.translation.x.clamp(-max_x, max_x);
+        transform.translation.y = transform.translation.y.clamp(-max_y, max_y);
+    }
+}
+
+// Camera initialization.
+fn init(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    export: Res<ExportRender>,
+    language: Res<Language>,
+    night_vision: Res<NightVision>,
+    text_size: Res<HelpTextSize>,
+    vignette: Res<Vignette>,
+) {
     commands.spawn((
         Camera2d,
-        projection,
+        MainCamera,
+        projection(),
         Light2d {
             ambient_light: AmbientLight2d {
-                brightness: AMBIENT_BRIGHTNESS,
+                brightness: night_vision.ambient(),
                 ..default()
             },
         },
     ));
 
+    // Render the same framing offscreen at the export resolution.
+    if export.enabled {
+        let size = Extent3d {
+            width: export.width,
+            height: export.height,
+            depth_or_array_layers: 1,
+        };
+        let mut image = Image::new_fill(
+            size,
+            TextureDimension::D2,
+            &[0, 0, 0, 0],
+            TextureFormat::Bgra8UnormSrgb,
+            RenderAssetUsages::default(),
+        );
+        image.texture_descriptor.usage = TextureUsages::TEXTURE_BINDING
+            | TextureUsages::COPY_DST
+            | TextureUsages::COPY_SRC
+            | TextureUsages::RENDER_ATTACHMENT;
+        let target = images.add(image);
+
+        commands.spawn((
+            Camera2d,
+            Camera {
+                order: -1,
+                target: RenderTarget::Image(target.clone().into()),
+                ..default()
+            },
+            projection(),
+            Light2d {
+                ambient_light: AmbientLight2d {
+                    brightness: night_vision.ambient(),
+                    ..default()
+                },
+            },
+        ));
+        commands.insert_resource(ExportTarget(target));
+    }
+
+    // Vignette over the whole window, kept beneath the rest of the UI.
+    commands.spawn((
+        ImageNode {
+            image: images.add(vignette_image()),
+            color: Color::WHITE.with_alpha(vignette.strength),
+            ..default()
+        },
+        Node {
+            position_type: PositionType::Absolute,
+            width: percent(100),
+            height: percent(100),
+            ..default()
+        },
+        GlobalZIndex(-1),
+        if vignette.enabled {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        },
+        VignetteOverlay,
+    ));
+
     // Display help UI in the upper right.
+    let [first_line, second_line] = language.help_lines();
     commands.spawn((
-        Text::new("move: left/right - interact: up"),
+        HelpText(0),
+        Text::new(first_line),
+        TextFont::from_font_size(text_size.font_size),
         Node {
             position_type: PositionType::Absolute,
             top: px(12),
@@ -43,7 +648,9 @@ fn init(mut commands: Commands) {
         },
     ));
     commands.spawn((
-        Text::new("or click to move and interact"),
+        HelpText(1),
+        Text::new(second_line),
+        TextFont::from_font_size(text_size.font_size),
         Node {
             position_type: PositionType::Absolute,
             top: px(35),
@@ -52,3 +659,151 @@ fn init(mut commands: Commands) {
         },
     ));
 }
+
+// Set a light's shadows and radius for the quality and radius scale, relative to its authored settings. Shadows are
+// picked from the authored radius so scaling the lights doesn't change which of them cast shadows.
+fn apply_shadow_quality(
+    quality: ShadowQuality,
+    radius_scale: LightRadiusScale,
+    defaults: &ShadowDefaults,
+    light: &mut PointLight2d,
+) {
+    light.cast_shadows = defaults.cast_shadows && defaults.radius >= quality.min_shadow_radius();
+    light.radius = defaults.radius * quality.radius_scale() * radius_scale.0.max(0.0);
+}
+
+// Black texture that's clear in the middle and darkens smoothly toward the edges.
+fn vignette_image() -> Image {
+    let mut data = Vec::with_capacity(usize::from(VIGNETTE_WIDTH) * usize::from(VIGNETTE_HEIGHT) * 4);
+    for y in 0..VIGNETTE_HEIGHT {
+        for x in 0..VIGNETTE_WIDTH {
+            let u = (f32::from(x) + 0.5) / f32::from(VIGNETTE_WIDTH) * 2.0 - 1.0;
+            let v = (f32::from(y) + 0.5) / f32::from(VIGNETTE_HEIGHT) * 2.0 - 1.0;
+            let distance = Vec2::new(u, v).length() / std::f32::consts::SQRT_2;
+            let t = ((distance - VIGNETTE_INNER) / (1.0 - VIGNETTE_INNER)).clamp(0.0, 1.0);
+            let alpha = t * t * (3.0 - 2.0 * t);
+            data.extend([0, 0, 0, (alpha * 255.0) as u8]);
+        }
+    }
+
+    let mut image = Image::new(
+        Extent3d {
+            width: u32::from(VIGNETTE_WIDTH),
+            height: u32::from(VIGNETTE_HEIGHT),
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    image.sampler = ImageSampler::linear();
+    image
+}
+
+// Fixed orthographic projection showing the whole scene.
+fn projection() -> Projection {
+    let mut ortho = OrthographicProjection::default_2d();
+    ortho.scaling_mode = ScalingMode::Fixed {
+        width: WINDOW_WIDTH,
+        height: WINDOW_HEIGHT,
+    };
+    Projection::Orthographic(ortho)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // App running only the zoom system, without a camera.
+    fn zoom_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<AccumulatedMouseScroll>()
+            .init_resource::<CameraZoom>()
+            .add_systems(Update, handle_zoom);
+        app
+    }
+
+    #[test]
+    fn zoom_stays_at_full_scene_without_input() {
+        let mut app = zoom_app();
+        for _ in 0..10 {
+            app.update();
+        }
+
+        assert_eq!(app.world().resource::<CameraZoom>().scale, 1.0);
+    }
+
+    #[test]
+    fn scrolling_zooms_in_one_step() {
+        let mut app = zoom_app();
+        app.world_mut().resource_mut::<AccumulatedMouseScroll>().delta.y = 3.0;
+        app.update();
+        app.world_mut().resource_mut::<AccumulatedMouseScroll>().delta.y = 0.0;
+        app.update();
+
+        let scale = app.world().resource::<CameraZoom>().scale;
+        assert!((scale - (1.0 - ZOOM_STEP)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn follow_preset_moves_the_camera_with_the_man() {
+        let mut app = zoom_app();
+        app.init_resource::<CameraMode>()
+            .init_resource::<CameraModeControls>()
+            .add_systems(
+                Update,
+                (handle_camera_mode, handle_camera_follow).chain().before(handle_zoom),
+            );
+        let camera = app
+            .world_mut()
+            .spawn((MainCamera, projection(), Transform::default()))
+            .id();
+        let man = app
+            .world_mut()
+            .spawn((TheMan, GlobalTransform::from_xyz(20.0, 0.0, 5.0)))
+            .id();
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyC);
+        app.update();
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().clear();
+        assert_eq!(*app.world().resource::<CameraMode>(), CameraMode::Follow);
+        assert_eq!(app.world().get::<Transform>(camera).unwrap().translation.x, 20.0);
+
+        *app.world_mut().get_mut::<GlobalTransform>(man).unwrap() = GlobalTransform::from_xyz(-25.0, 0.0, 5.0);
+        app.update();
+        assert_eq!(app.world().get::<Transform>(camera).unwrap().translation.x, -25.0);
+    }
+
+    #[test]
+    fn main_camera_shows_the_fixed_scene() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<Assets<Image>>()
+            .init_resource::<ExportRender>()
+            .init_resource::<HelpTextSize>()
+            .init_resource::<Language>()
+            .init_resource::<NightVision>()
+            .init_resource::<Vignette>()
+            .add_systems(Startup, init);
+        app.update();
+
+        let projection = app
+            .world_mut()
+            .query_filtered::<&Projection, With<MainCamera>>()
+            .single(app.world())
+            .unwrap();
+        let Projection::Orthographic(ortho) = projection else {
+            panic!("main camera isn't orthographic");
+        };
+        assert!(matches!(
+            ortho.scaling_mode,
+            ScalingMode::Fixed { width, height } if width == WINDOW_WIDTH && height == WINDOW_HEIGHT
+        ));
+        assert_eq!((WINDOW_WIDTH, WINDOW_HEIGHT), (300.0, 150.0));
+    }
+}
@@ -1,6 +1,10 @@
 use bevy::prelude::*;
 
-use crate::interaction::{Interactable, State};
+use crate::{
+    interaction::{Interactable, State},
+    theman::{SeatIdle, SeatPose},
+    y_sort::YSorted,
+};
 
 #[derive(Component)]
 struct Chair;
@@ -13,7 +17,11 @@ pub fn add_systems(app: &mut App) {
 }
 
 // Animation initialization.
-fn init(mut commands: Commands, asset_server: Res<AssetServer>) {
+fn init(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut texture_layouts: ResMut<Assets<TextureAtlasLayout>>,
+) {
     // Create the chair sprite.
     // NOTE: not sure why the chair interaction width is so weird.
     commands.spawn((
@@ -22,7 +30,11 @@ fn init(mut commands: Commands, asset_server: Res<AssetServer>) {
             texture_atlas: None,
             ..default()
         },
-        Transform::from_xyz(70.0, -58.0, 5.0),
+        Transform::from_xyz(70.0, -58.0, 0.0),
+        YSorted {
+            layer: 6.0,
+            foot_offset: -12.5,
+        },
         Chair,
         State::Off,
         Interactable {
@@ -33,5 +45,17 @@ fn init(mut commands: Commands, asset_server: Res<AssetServer>) {
             sprite_width: 25.0,
             ..default()
         },
+        SeatPose {
+            sprite: asset_server.load("theman/theman_sitting_animation.png"),
+            layout: texture_layouts.add(TextureAtlasLayout::from_grid(UVec2::splat(32), 5, 1, None, None)),
+            fps: 10,
+            offset: Vec2::new(4.0, 2.0),
+            lean: 0.0,
+            idle: Some(SeatIdle {
+                sprite: asset_server.load("theman/theman_seated_idle.png"),
+                layout: texture_layouts.add(TextureAtlasLayout::from_grid(UVec2::splat(32), 4, 1, None, None)),
+                fps: 2,
+            }),
+        },
     ));
 }
@@ -0,0 +1,80 @@
+use bevy::prelude::*;
+
+use crate::{
+    interaction::{Interactable, State},
+    theman::SeatPose,
+    y_sort::YSorted,
+};
+
+#[derive(Component)]
+struct Couch;
+
+// Couch placement, there's no free floor in the default scene so it's left out unless enabled. The stereo's spot is
+// the default for scenes that disable the stereo.
+#[derive(Clone, Resource)]
+pub struct CouchConfig {
+    pub enabled: bool,
+    pub position: Vec2,
+}
+
+impl Default for CouchConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            position: Vec2::new(18.0, -62.0),
+        }
+    }
+}
+
+pub const INTERACTABLE_ID: &str = "couch";
+
+// Add the animation systems.
+pub fn add_systems(app: &mut App) {
+    app.init_resource::<CouchConfig>().add_systems(Startup, init);
+}
+
+// Animation initialization.
+fn init(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    config: Res<CouchConfig>,
+    mut texture_layouts: ResMut<Assets<TextureAtlasLayout>>,
+) {
+    if !config.enabled {
+        return;
+    }
+
+    // Create the couch sprite, the man reclines on the left cushion, lower and leaning back against the arm without the
+    // chair's seated idle.
+    // NOTE: there's no reclining art yet, so the pose tilts the sitting animation back until it's drawn.
+    commands.spawn((
+        Sprite {
+            image: asset_server.load("couch/couch.png"),
+            texture_atlas: None,
+            ..default()
+        },
+        Transform::from_translation(config.position.extend(0.0)),
+        YSorted {
+            layer: 6.0,
+            foot_offset: -10.0,
+        },
+        Couch,
+        State::Off,
+        Interactable {
+            id: INTERACTABLE_ID.to_string(),
+            height: 20.0,
+            width: 34.0,
+            sprite_height: 20.0,
+            sprite_width: 40.0,
+            ..default()
+        },
+        SeatPose {
+            sprite: asset_server.load("theman/theman_sitting_animation.png"),
+            layout: texture_layouts.add(TextureAtlasLayout::from_grid(UVec2::splat(32), 5, 1, None, None)),
+            fps: 6,
+            offset: Vec2::new(-10.0, -3.0),
+            lean: 0.35,
+            idle: None,
+        },
+    ));
+}
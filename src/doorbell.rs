@@ -0,0 +1,137 @@
+use bevy::prelude::*;
+
+use crate::{
+    audio::{AudioConfig, play_one_shot},
+    house::SceneRegions,
+    interaction::{Interactable, InteractionEvent, State},
+    santa::SantasHereEvent,
+};
+
+#[derive(Component)]
+struct Doorbell;
+
+// Doorbell settings, the sound is an asset path and rings on every press outside the cooldown. With summon_santa set
+// Santa arrives delay seconds after a ring. There's no doorbell sound shipped so it's left out unless enabled.
+#[derive(Clone, Resource)]
+pub struct DoorbellConfig {
+    pub enabled: bool,
+    pub sound: Option<String>,
+    pub volume: f32,
+    pub cooldown: f32,
+    pub summon_santa: bool,
+    pub delay: f32,
+}
+
+impl Default for DoorbellConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            sound: None,
+            volume: 0.6,
+            cooldown: 2.0,
+            summon_santa: false,
+            delay: 1.5,
+        }
+    }
+}
+
+// Time left before the doorbell can ring again and before a summoned Santa arrives.
+#[derive(Default, Resource)]
+struct DoorbellTimers {
+    cooldown: Option<Timer>,
+    arrival: Option<Timer>,
+}
+
+pub const INTERACTABLE_ID: &str = "doorbell";
+
+// Offset of the doorbell from the door, it's mounted on the inner face of the wall.
+const DOOR_OFFSET: Vec2 = Vec2::new(4.0, -48.0);
+
+// Add the doorbell systems.
+pub fn add_systems(app: &mut App) {
+    app.init_resource::<DoorbellConfig>()
+        .init_resource::<DoorbellTimers>()
+        .add_systems(Startup, init)
+        .add_systems(Update, (handle_interaction, handle_timers));
+}
+
+// Ring the doorbell on interaction unless it's cooling down, the doorbell has no state to toggle.
+fn handle_interaction(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    audio_config: Res<AudioConfig>,
+    config: Res<DoorbellConfig>,
+    mut timers: ResMut<DoorbellTimers>,
+    mut events: MessageReader<InteractionEvent>,
+) {
+    for event in events.read() {
+        if event.id != INTERACTABLE_ID || timers.cooldown.is_some() {
+            continue;
+        }
+
+        if let Some(sound) = &config.sound {
+            play_one_shot(
+                &mut commands,
+                &audio_config,
+                asset_server.load(sound.clone()),
+                config.volume,
+            );
+        }
+
+        timers.cooldown = Some(Timer::from_seconds(config.cooldown, TimerMode::Once));
+        if config.summon_santa {
+            timers.arrival = Some(Timer::from_seconds(config.delay, TimerMode::Once));
+        }
+    }
+}
+
+// Count down the cooldown and summon Santa once the delay after a ring is up.
+fn handle_timers(
+    time: Res<Time>,
+    mut timers: ResMut<DoorbellTimers>,
+    mut santa_events: MessageWriter<SantasHereEvent>,
+) {
+    if let Some(timer) = &mut timers.cooldown
+        && timer.tick(time.delta()).just_finished()
+    {
+        timers.cooldown = None;
+    }
+
+    if let Some(timer) = &mut timers.arrival
+        && timer.tick(time.delta()).just_finished()
+    {
+        timers.arrival = None;
+        santa_events.write(SantasHereEvent);
+    }
+}
+
+// Doorbell initialization.
+fn init(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    config: Res<DoorbellConfig>,
+    regions: Res<SceneRegions>,
+) {
+    if !config.enabled {
+        return;
+    }
+
+    // The doorbell stays off, it's only there to be pressed.
+    commands.spawn((
+        Sprite {
+            image: asset_server.load("doorbell/doorbell.png"),
+            ..default()
+        },
+        Transform::from_xyz(regions.door_x + DOOR_OFFSET.x, DOOR_OFFSET.y, 5.0),
+        Doorbell,
+        State::Off,
+        Interactable {
+            id: INTERACTABLE_ID.to_string(),
+            height: 6.0,
+            width: 4.0,
+            sprite_height: 6.0,
+            sprite_width: 4.0,
+            ..default()
+        },
+    ));
+}
@@ -4,8 +4,11 @@ use rand::Rng;
 
 use crate::{
     animation::AnimationConfig,
+    audio::{AudioConfig, AudioUnlocked, sound_change},
     flickering_light::FlickeringLight,
-    interaction::{Interactable, InteractionEvent, State},
+    interaction::{InitialStates, Interactable, InteractionEvent, State},
+    snow::SnowParticle,
+    theman::SolidObstacle,
 };
 
 #[derive(Clone, Resource)]
@@ -16,9 +19,46 @@ struct SpriteAssets {
 }
 
 #[derive(Component)]
-struct Fireplace;
+pub struct Fireplace;
 
-const INTERACTABLE_ID: &str = "fireplace";
+// Chimney smoke settings, puffs are spawned every spawn_interval seconds while the fire is lit.
+#[derive(Clone, Resource)]
+pub struct ChimneySmoke {
+    pub position: Vec2,
+    pub spawn_interval: f32,
+    pub lifetime: f32,
+    pub rise_speed: f32,
+}
+
+impl Default for ChimneySmoke {
+    fn default() -> Self {
+        Self {
+            position: Vec2::new(-25.0, 56.0),
+            spawn_interval: 0.5,
+            lifetime: 5.0,
+            rise_speed: 5.0,
+        }
+    }
+}
+
+// A puff of smoke that fades out over its lifetime, rising and drifting as a snow particle with a negative fall speed.
+#[derive(Component)]
+struct SmokePuff {
+    age: f32,
+}
+
+#[derive(Resource)]
+struct SmokeTimer(Timer);
+
+pub const INTERACTABLE_ID: &str = "fireplace";
+
+// Half width of the hearth the man can't walk into while it's lit, narrower than the interaction box so he can still
+// reach the fire from either side without the light switch in the way.
+const FIRE_HALF_WIDTH: f32 = 8.0;
+
+// Drift speed and starting opacity of smoke puffs.
+const SMOKE_DRIFT: f32 = 2.0;
+const SMOKE_OPACITY: f32 = 0.35;
 
 // Light effect colors.
 const LIGHT_COLORS: [Color; 3] = [
@@ -29,15 +69,19 @@ const LIGHT_COLORS: [Color; 3] = [
 
 // Add the animation systems.
 pub fn add_systems(app: &mut App) {
-    app.add_systems(Startup, init).add_systems(
-        Update,
-        (
-            handle_animations,
-            handle_interaction,
-            handle_sound,
-            handle_light.in_set(crate::flickering_light::LightInsertionSet),
-        ),
-    );
+    app.init_resource::<ChimneySmoke>()
+        .add_systems(Startup, init)
+        .add_systems(
+            Update,
+            (
+                handle_animations,
+                handle_interaction,
+                handle_sound,
+                handle_light.in_set(crate::flickering_light::LightInsertionSet),
+                handle_smoke,
+                handle_smoke_puffs,
+            ),
+        );
 }
 
 // Manage the animation frame timing.
@@ -67,19 +111,23 @@ fn handle_animations(time: Res<Time>, mut query: Query<(&mut AnimationConfig, &m
     }
 }
 
-// Listen for interaction events and update the state.
+// Listen for interaction events and update the state, the lit fire is a solid obstacle.
 fn handle_interaction(
+    mut commands: Commands,
     sprite_assets: Res<SpriteAssets>,
     mut events: MessageReader<InteractionEvent>,
-    mut query: Query<(&mut State, &mut Sprite), With<Fireplace>>,
+    mut query: Query<(Entity, &mut State, &mut Sprite), With<Fireplace>>,
 ) {
     for event in events.read() {
         if event.id == INTERACTABLE_ID
-            && let Ok((mut state, mut sprite)) = query.single_mut()
+            && let Ok((entity, mut state, mut sprite)) = query.single_mut()
         {
             match *state {
                 State::Off => {
                     *state = State::On;
+                    commands.entity(entity).insert(SolidObstacle {
+                        half_width: FIRE_HALF_WIDTH,
+                    });
                     sprite.image = sprite_assets.running_sprite.clone();
                     sprite.texture_atlas = Some(TextureAtlas {
                         layout: sprite_assets.running_layout.clone(),
@@ -89,6 +137,7 @@ fn handle_interaction(
 
                 State::On => {
                     *state = State::Off;
+                    commands.entity(entity).remove::<SolidObstacle>();
                     sprite.image = sprite_assets.off_sprite.clone();
                     sprite.texture_atlas = None;
                 }
@@ -97,19 +146,14 @@ fn handle_interaction(
     }
 }
 
-// Control audio playback based on fireplace state
-fn handle_sound(query: Query<(&State, &mut SpatialAudioSink), (With<Fireplace>, Changed<State>)>) {
+// Control audio playback based on fireplace state.
+// NOTE: reconciled every frame so quick toggles, or a sink created after the toggle, can't leave it out of sync.
+fn handle_sound(unlocked: Res<AudioUnlocked>, query: Query<(&State, &SpatialAudioSink), With<Fireplace>>) {
     for (state, audio_sink) in &query {
-        match *state {
-            // Start the fireplace sound effect if it isn't already running.
-            State::On => {
-                audio_sink.play();
-            }
-
-            // Remove any existing sound effects.
-            State::Off => {
-                audio_sink.pause();
-            }
+        match sound_change(*state, audio_sink.is_paused(), unlocked.0) {
+            Some(true) => audio_sink.play(),
+            Some(false) => audio_sink.pause(),
+            None => {}
         }
     }
 }
@@ -146,10 +190,67 @@ fn handle_light(
     }
 }
 
+// Spawn smoke puffs at the chimney while the fire is lit.
+fn handle_smoke(
+    mut commands: Commands,
+    time: Res<Time>,
+    smoke: Res<ChimneySmoke>,
+    mut timer: ResMut<SmokeTimer>,
+    query: Query<&State, With<Fireplace>>,
+) {
+    if !query.iter().any(|state| *state == State::On) {
+        return;
+    }
+
+    timer.0.tick(time.delta());
+    if !timer.0.just_finished() {
+        return;
+    }
+
+    let mut rng = rand::rng();
+    commands.spawn((
+        Sprite {
+            color: Color::srgba(0.7, 0.7, 0.75, SMOKE_OPACITY),
+            custom_size: Some(Vec2::splat(rng.random_range(2.0..=4.0))),
+            ..default()
+        },
+        Transform::from_translation(smoke.position.extend(2.2)),
+        SmokePuff { age: 0.0 },
+        SnowParticle {
+            fall_speed: -smoke.rise_speed,
+            drift_speed: SMOKE_DRIFT,
+            drift_phase: rng.random_range(0.0..=std::f32::consts::TAU),
+        },
+    ));
+}
+
+// Fade the smoke puffs, despawning them at the end of their lifetime. The snow systems move them.
+fn handle_smoke_puffs(
+    mut commands: Commands,
+    time: Res<Time>,
+    smoke: Res<ChimneySmoke>,
+    mut query: Query<(Entity, &mut SmokePuff, &mut Sprite)>,
+) {
+    for (entity, mut puff, mut sprite) in &mut query {
+        puff.age += time.delta_secs();
+        if puff.age >= smoke.lifetime {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        sprite
+            .color
+            .set_alpha(SMOKE_OPACITY * (1.0 - puff.age / smoke.lifetime));
+    }
+}
+
 // Animation initialization.
 fn init(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
+    audio_config: Res<AudioConfig>,
+    initial_states: Res<InitialStates>,
+    smoke: Res<ChimneySmoke>,
     mut texture_layouts: ResMut<Assets<TextureAtlasLayout>>,
 ) {
     // Load the running sprite sheet.
@@ -159,22 +260,37 @@ fn init(
         off_sprite: asset_server.load("fireplace/fireplace.png"),
     };
     commands.insert_resource(sprite.clone());
+    commands.insert_resource(SmokeTimer(Timer::from_seconds(
+        smoke.spawn_interval.max(0.01),
+        TimerMode::Repeating,
+    )));
 
-    // Create the sprite starting in the off state.
-    commands.spawn((
+    // Create the sprite in its configured starting state, the light and sound follow the state once spawned.
+    let state = initial_states.get(INTERACTABLE_ID);
+    let (image, texture_atlas) = match state {
+        State::On => (
+            sprite.running_sprite,
+            Some(TextureAtlas {
+                layout: sprite.running_layout,
+                index: 0,
+            }),
+        ),
+        State::Off => (sprite.off_sprite, None),
+    };
+    let mut fireplace = commands.spawn((
         Sprite {
-            image: sprite.off_sprite,
-            texture_atlas: None,
+            image,
+            texture_atlas,
             ..default()
         },
         Transform::from_translation(Vec3::new(116.0, -31.0, 5.0)),
         Fireplace,
         AnimationConfig::new(0, 4, 6),
-        State::Off,
+        state,
         AudioPlayer::new(asset_server.load("fireplace/fire.ogg")),
         PlaybackSettings::LOOP
             .with_spatial(true)
-            .with_volume(Volume::Linear(0.75))
+            .with_volume(Volume::Linear(audio_config.fireplace))
             .paused(),
         Interactable {
             id: INTERACTABLE_ID.to_string(),
@@ -192,4 +308,65 @@ fn init(
             ..default()
         },
     ));
+
+    if state == State::On {
+        fireplace.insert(SolidObstacle {
+            half_width: FIRE_HALF_WIDTH,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn light_flickers_while_on_and_goes_dark_when_off() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins).add_systems(Update, handle_light);
+        let fireplace = app
+            .world_mut()
+            .spawn((
+                Fireplace,
+                State::On,
+                PointLight2d {
+                    intensity: 1.0,
+                    ..default()
+                },
+            ))
+            .id();
+
+        app.update();
+        assert!(app.world().get::<FlickeringLight>(fireplace).is_some());
+
+        *app.world_mut().get_mut::<State>(fireplace).unwrap() = State::Off;
+        app.update();
+        assert!(app.world().get::<FlickeringLight>(fireplace).is_none());
+        assert_eq!(app.world().get::<PointLight2d>(fireplace).unwrap().intensity, 0.0);
+    }
+
+    // Run the playback reconciliation over a state per frame, returning whether the sink ends up paused.
+    fn paused_after(states: &[State]) -> bool {
+        let mut paused = true;
+        for &state in states {
+            if let Some(play) = sound_change(state, paused, true) {
+                paused = !play;
+            }
+        }
+        paused
+    }
+
+    #[test]
+    fn sound_ends_paused_after_toggling_off() {
+        assert!(!paused_after(&[State::On]));
+        assert!(paused_after(&[State::On, State::Off]));
+        assert!(paused_after(&[State::On, State::Off, State::Off]));
+        assert!(!paused_after(&[State::On, State::Off, State::On]));
+    }
+
+    #[test]
+    fn sound_waits_for_audio_to_unlock() {
+        assert_eq!(sound_change(State::On, true, false), None);
+        assert_eq!(sound_change(State::On, true, true), Some(true));
+    }
 }
@@ -1,7 +1,7 @@
 use bevy::prelude::*;
 use bevy_light_2d::prelude::*;
 
-use crate::noise;
+use crate::{camera::NightVision, noise};
 
 #[derive(Component)]
 pub struct FlickeringLight {
@@ -18,12 +18,48 @@ pub struct FlickeringLight {
     pub time_offset: f32,
 }
 
+// Slow intensity-only variation for spotlights, wandering around the base intensity by up to the amplitude as a
+// fraction of it.
+#[derive(Component)]
+pub struct FlickeringSpotLight {
+    pub seed: f32,
+    pub base_intensity: f32,
+    pub intensity_amplitude: f32,
+    pub intensity_frequency: f32,
+    pub intensity_octaves: u32,
+}
+
+// Pins a flickering light to a fixed color and intensity, takes precedence over FlickeringLight while both are present.
+// NOTE: it's only applied alongside FlickeringLight, so a light that's off stays dark. Every prop inserts
+// FlickeringLight when it turns a light on, which is when the override takes effect.
+#[derive(Component)]
+pub struct StaticLightOverride {
+    pub color: Color,
+    pub intensity: f32,
+}
+
 #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct LightInsertionSet;
 
+// Scales applied to the seed when offsetting the noise sampling coordinates, irrational-ish so nearby seeds land in
+// unrelated parts of the noise field.
+const SEED_X_SCALE: f32 = 7.31;
+const SEED_Y_SCALE: f32 = 1.618;
+
+// Small, faster intensity jitter layered over the main flicker, relative to the intensity amplitude.
+const JITTER_AMOUNT: f32 = 0.15;
+const JITTER_FREQUENCY: f32 = 3.7;
+const JITTER_SEED_OFFSET: f32 = 500.0;
+
 // Add the animation systems.
 pub fn add_systems(app: &mut App) {
-    app.add_systems(Update, handle_light_flicker.after(LightInsertionSet));
+    app.add_systems(
+        Update,
+        (
+            handle_light_flicker.after(LightInsertionSet),
+            handle_spot_light_flicker.after(LightInsertionSet),
+        ),
+    );
 }
 
 // Blend the colors using weights.
@@ -42,16 +78,40 @@ fn blend_colors(colors: &Vec<Color>, weights: &Vec<f32>) -> Color {
     Color::srgb(r, g, b)
 }
 
-// Apply noise-based flicker to the light color and intensity.
-fn handle_light_flicker(time: Res<Time>, mut query: Query<(&mut PointLight2d, &FlickeringLight)>) {
-    for (mut light, params) in &mut query {
+// Apply noise-based flicker to the light color and intensity, overridden lights are held at their fixed values.
+fn handle_light_flicker(
+    time: Res<Time>,
+    night_vision: Res<NightVision>,
+    mut query: Query<(&mut PointLight2d, &FlickeringLight, Option<&StaticLightOverride>)>,
+) {
+    for (mut light, params, light_override) in &mut query {
+        if let Some(light_override) = light_override {
+            light.color = light_override.color;
+            light.intensity = light_override.intensity * night_vision.intensity_multiplier();
+            continue;
+        }
+
         let time = time.elapsed_secs() + params.time_offset;
 
-        // Intensity randomization.
-        let intensity_noise = noise::generate(time * params.intensity_frequency, params.seed, params.intensity_octaves);
-        light.intensity = intensity_noise.mul_add(params.intensity_amplitude, params.intensity_min);
+        // Intensity randomization, sampled away from other lights so nearby seeds don't flicker in sync.
+        let intensity_noise = noise::generate(
+            time.mul_add(params.intensity_frequency, params.seed * SEED_X_SCALE),
+            params.seed * SEED_Y_SCALE,
+            params.intensity_octaves,
+        );
+        let jitter = noise::generate(
+            time * params.intensity_frequency * JITTER_FREQUENCY,
+            params.seed + JITTER_SEED_OFFSET,
+            1,
+        ) * JITTER_AMOUNT;
+        light.intensity = (intensity_noise + jitter).mul_add(params.intensity_amplitude, params.intensity_min)
+            * night_vision.intensity_multiplier();
 
-        // Color randomization.
+        // Color randomization, a light without colors keeps its current color.
+        if params.colors.is_empty() {
+            warn_once!("flickering light has no colors, keeping its current color");
+            continue;
+        }
         light.color = blend_colors(
             &params.colors,
             &weights(
@@ -67,6 +127,19 @@ fn handle_light_flicker(time: Res<Time>, mut query: Query<(&mut PointLight2d, &F
     }
 }
 
+// Vary spotlight intensities around their base.
+fn handle_spot_light_flicker(time: Res<Time>, mut query: Query<(&mut SpotLight2d, &FlickeringSpotLight)>) {
+    for (mut light, params) in &mut query {
+        let noise = noise::generate(
+            time.elapsed_secs()
+                .mul_add(params.intensity_frequency, params.seed * SEED_X_SCALE),
+            params.seed * SEED_Y_SCALE,
+            params.intensity_octaves,
+        );
+        light.intensity = (params.base_intensity * noise.mul_add(params.intensity_amplitude, 1.0)).max(0.0);
+    }
+}
+
 // Apply softmax normalization with a temperature parameter.
 fn softmax(logits: &[f32], temperature: f32) -> Vec<f32> {
     let scaled_logits: Vec<f32> = logits.iter().map(|x| x / temperature).collect();
@@ -97,3 +170,78 @@ fn weights(
 
     softmax(&logits, temperature)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    // Headless app stepping the flicker a tenth of a second per update.
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)))
+            .init_resource::<NightVision>()
+            .add_systems(Update, handle_light_flicker);
+        app
+    }
+
+    // Firelight-like flicker with the given seed and colors.
+    fn flicker(seed: f32, colors: Vec<Color>) -> FlickeringLight {
+        FlickeringLight {
+            seed,
+            intensity_amplitude: 0.4,
+            intensity_frequency: 2.0,
+            intensity_min: 0.6,
+            intensity_octaves: 4,
+            color_frequency: 1.0,
+            color_octaves: 2,
+            color_seed_offset: 100.0,
+            color_temperature: 0.2,
+            colors,
+            time_offset: 0.0,
+        }
+    }
+
+    #[test]
+    fn lights_with_nearby_seeds_flicker_apart() {
+        let mut app = test_app();
+        let lights = [10.0, 11.0].map(|seed| {
+            app.world_mut()
+                .spawn((PointLight2d::default(), flicker(seed, vec![Color::WHITE])))
+                .id()
+        });
+
+        let frames = 30;
+        let mut difference = 0.0;
+        for _ in 0..frames {
+            app.update();
+            let [first, second] = lights.map(|light| app.world().get::<PointLight2d>(light).unwrap().intensity);
+            difference += (first - second).abs();
+        }
+
+        assert!(
+            difference / frames as f32 > 0.03,
+            "mean difference {}",
+            difference / frames as f32
+        );
+    }
+
+    #[test]
+    fn light_without_colors_keeps_its_color() {
+        let mut app = test_app();
+        let color = Color::srgb(1.0, 0.5, 0.2);
+        let light = app
+            .world_mut()
+            .spawn((PointLight2d { color, ..default() }, flicker(10.0, Vec::new())))
+            .id();
+
+        for _ in 0..5 {
+            app.update();
+            let point_light = app.world().get::<PointLight2d>(light).unwrap();
+            assert_eq!(point_light.color, color);
+            assert!(point_light.intensity.is_finite());
+        }
+    }
+}
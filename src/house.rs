@@ -1,16 +1,158 @@
 use bevy::prelude::*;
 use bevy_light_2d::prelude::*;
 
+use crate::{
+    fireplace, house_lights,
+    interaction::{Interactable, State},
+    snow::{SnowBoost, SnowIntensity},
+    stereo,
+};
+
 #[derive(Component)]
 struct Background;
 
+// Fades the roof snow in over time.
+#[derive(Component)]
+struct RoofSnowFade(Timer);
+
+// Warm glow behind the windows while any indoor light is on, fading over fade_seconds.
+#[derive(Clone, Resource)]
+pub struct WindowGlow {
+    pub color: Color,
+    pub fade_seconds: f32,
+}
+
+impl Default for WindowGlow {
+    fn default() -> Self {
+        Self {
+            color: Color::srgb(1.0, 0.7, 0.35),
+            fade_seconds: 1.5,
+        }
+    }
+}
+
+#[derive(Component)]
+struct WindowPane;
+
+// Props whose lights make the windows glow.
+const INDOOR_LIGHT_IDS: [&str; 3] = [
+    fireplace::INTERACTABLE_ID,
+    house_lights::INTERACTABLE_ID,
+    stereo::INTERACTABLE_ID,
+];
+
+// Window centres and size in the house sprite, and the glow opacity once fully faded in.
+const WINDOW_CENTERS: [Vec2; 2] = [Vec2::new(-22.0, -28.0), Vec2::new(58.0, -28.0)];
+const WINDOW_SIZE: Vec2 = Vec2::new(40.0, 48.0);
+const WINDOW_GLOW_ALPHA: f32 = 0.35;
+
+// Which side of the house walls a point is on.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Region {
+    Indoor,
+    Outdoor,
+}
+
+// Extent of the house interior along x and the door in its left wall, shared by everything that needs to know whether
+// something is inside or outside.
+#[derive(Clone, Resource)]
+pub struct SceneRegions {
+    pub indoor_min_x: f32,
+    pub indoor_max_x: f32,
+    pub door_x: f32,
+}
+
+impl Default for SceneRegions {
+    fn default() -> Self {
+        Self {
+            indoor_min_x: -94.0,
+            indoor_max_x: 173.0,
+            door_x: -90.0,
+        }
+    }
+}
+
+impl SceneRegions {
+    // Region of the x position, the walls themselves count as outdoors.
+    pub fn region_at(&self, x: f32) -> Region {
+        if x > self.indoor_min_x && x < self.indoor_max_x {
+            Region::Indoor
+        } else {
+            Region::Outdoor
+        }
+    }
+}
+
+// Seconds for the snow on the roof to build up to full opacity, it builds up faster while the snow is boosted.
+#[derive(Clone, Resource)]
+pub struct RoofSnow {
+    pub seconds: f32,
+}
+
+impl Default for RoofSnow {
+    fn default() -> Self {
+        Self { seconds: 60.0 * 10.0 }
+    }
+}
+
 // Add the animation systems.
 pub fn add_systems(app: &mut App) {
-    app.add_systems(Startup, init);
+    app.init_resource::<RoofSnow>()
+        .init_resource::<SceneRegions>()
+        .init_resource::<WindowGlow>()
+        .add_systems(Startup, init)
+        .add_systems(Update, (handle_roof_snow, handle_window_glow));
+}
+
+// Handle the roof snow fading in over time, scaled by how heavily it's snowing when the snow systems are present.
+fn handle_roof_snow(
+    time: Res<Time>,
+    mut commands: Commands,
+    intensity: Option<Res<SnowIntensity>>,
+    boost: Option<Res<SnowBoost>>,
+    mut query: Query<(Entity, &mut RoofSnowFade, &mut Sprite)>,
+) {
+    let snowfall = match (intensity, boost) {
+        (Some(intensity), Some(boost)) => intensity.snowfall(&boost),
+        _ => 1.0,
+    };
+
+    for (entity, mut fade, mut sprite) in &mut query {
+        fade.0.tick(time.delta().mul_f32(snowfall));
+        sprite.color.set_alpha(fade.0.fraction());
+
+        if fade.0.just_finished() {
+            commands.entity(entity).remove::<RoofSnowFade>();
+        }
+    }
+}
+
+// Fade the window glow in while any indoor light is on and out once they're all off.
+fn handle_window_glow(
+    time: Res<Time>,
+    glow: Res<WindowGlow>,
+    props: Query<(&Interactable, &State)>,
+    mut panes: Query<&mut Sprite, With<WindowPane>>,
+) {
+    let lit = props
+        .iter()
+        .any(|(interactable, state)| *state == State::On && INDOOR_LIGHT_IDS.contains(&interactable.id.as_str()));
+    let target = if lit { WINDOW_GLOW_ALPHA } else { 0.0 };
+    let step = WINDOW_GLOW_ALPHA * time.delta_secs() / glow.fade_seconds.max(0.01);
+
+    for mut sprite in &mut panes {
+        let alpha = sprite.color.alpha();
+        let alpha = if alpha < target {
+            (alpha + step).min(target)
+        } else {
+            (alpha - step).max(target)
+        };
+        sprite.color = glow.color.with_alpha(alpha);
+    }
 }
 
 // House initialization.
-fn init(mut commands: Commands, asset_server: Res<AssetServer>) {
+fn init(mut commands: Commands, asset_server: Res<AssetServer>, roof_snow: Res<RoofSnow>, glow: Res<WindowGlow>) {
     // Create the house.
     let background = asset_server.load("house/house.png");
     commands.spawn((
@@ -22,6 +164,31 @@ fn init(mut commands: Commands, asset_server: Res<AssetServer>) {
         Background,
     ));
 
+    // Snow on the roof, starting clear and building up.
+    commands.spawn((
+        Sprite {
+            image: asset_server.load("house/roof_snow.png"),
+            color: Color::WHITE.with_alpha(0.0),
+            ..default()
+        },
+        Transform::from_xyz(0.0, 0.0, 2.1),
+        Background,
+        RoofSnowFade(Timer::from_seconds(roof_snow.seconds, TimerMode::Once)),
+    ));
+
+    // Window glow behind the house so it only shows through the windows.
+    for center in WINDOW_CENTERS {
+        commands.spawn((
+            Sprite {
+                color: glow.color.with_alpha(0.0),
+                custom_size: Some(WINDOW_SIZE),
+                ..default()
+            },
+            Transform::from_translation(center.extend(1.9)),
+            WindowPane,
+        ));
+    }
+
     // Create three (floor is ignored) rectangle occluders to block light from crossing the house boundaries.
     commands.spawn((
         LightOccluder2d {
@@ -59,3 +226,58 @@ fn init(mut commands: Commands, asset_server: Res<AssetServer>) {
         ));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    // Roof snow alpha after a second of a ten second build up at the snow intensity.
+    fn roof_snow_alpha(intensity: f32) -> f32 {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)))
+            .insert_resource(SnowIntensity(intensity))
+            .init_resource::<SnowBoost>()
+            .add_systems(Update, handle_roof_snow);
+        let roof = app
+            .world_mut()
+            .spawn((
+                Sprite::default(),
+                RoofSnowFade(Timer::from_seconds(10.0, TimerMode::Once)),
+            ))
+            .id();
+
+        // The first update only starts the clock.
+        for _ in 0..11 {
+            app.update();
+        }
+        app.world().get::<Sprite>(roof).unwrap().color.alpha()
+    }
+
+    #[test]
+    fn roof_snow_builds_up_faster_while_the_snow_is_boosted() {
+        assert!((roof_snow_alpha(0.0) - 0.1).abs() < 1e-3);
+        assert!((roof_snow_alpha(1.0) - 0.15).abs() < 1e-3);
+    }
+
+    #[test]
+    fn region_at_counts_the_walls_as_outdoors() {
+        let regions = SceneRegions::default();
+
+        assert_eq!(regions.region_at(regions.indoor_min_x), Region::Outdoor);
+        assert_eq!(regions.region_at(regions.indoor_max_x), Region::Outdoor);
+        assert_eq!(regions.region_at(regions.indoor_min_x - 1.0), Region::Outdoor);
+        assert_eq!(regions.region_at(regions.indoor_max_x + 1.0), Region::Outdoor);
+    }
+
+    #[test]
+    fn region_at_is_indoors_just_inside_the_walls() {
+        let regions = SceneRegions::default();
+
+        assert_eq!(regions.region_at(regions.indoor_min_x + 0.01), Region::Indoor);
+        assert_eq!(regions.region_at(regions.indoor_max_x - 0.01), Region::Indoor);
+        assert_eq!(regions.region_at(regions.door_x + 1.0), Region::Indoor);
+    }
+}
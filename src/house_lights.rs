@@ -1,10 +1,11 @@
-use bevy::{audio::Volume, prelude::*};
+use bevy::prelude::*;
 use bevy_light_2d::prelude::*;
 use rand::Rng;
 
 use crate::{
+    audio::{AudioConfig, play_one_shot},
     flickering_light::FlickeringLight,
-    interaction::{InRange, Interactable, InteractionEvent, State},
+    interaction::{InitialStates, Interactable, InteractionEvent, State},
 };
 
 #[derive(Clone, Resource)]
@@ -38,9 +39,27 @@ enum XmasLightColor {
 #[derive(Component)]
 struct XmasLight(XmasLightColor);
 
-const INTERACTABLE_ID: &str = "light-switch";
+// Layout of the Xmas light string, positions are relative to the light switch and colors cycle along the string.
+#[derive(Clone, Resource)]
+pub struct XmasLightString {
+    pub bulb_count: u16,
+    pub spacing: f32,
+    pub start_x: f32,
+    pub y: f32,
+}
 
-const SWITCH_VOLUME: f32 = 0.40;
+impl Default for XmasLightString {
+    fn default() -> Self {
+        Self {
+            bulb_count: 40,
+            spacing: 7.0,
+            start_x: -164.0,
+            y: 55.0,
+        }
+    }
+}
+
+pub const INTERACTABLE_ID: &str = "light-switch";
 
 // Light effect colors.
 const ATTIC_LIGHT_COLORS: [Color; 3] = [
@@ -69,13 +88,15 @@ const XMAS_LIGHT_GREEN_COLORS: [Color; 3] = [
 
 // Add the animation systems.
 pub fn add_systems(app: &mut App) {
-    app.add_systems(Startup, init).add_systems(
-        Update,
-        (
-            handle_interaction,
-            handle_light.in_set(crate::flickering_light::LightInsertionSet),
-        ),
-    );
+    app.init_resource::<XmasLightString>()
+        .add_systems(Startup, init)
+        .add_systems(
+            Update,
+            (
+                handle_interaction,
+                handle_light.in_set(crate::flickering_light::LightInsertionSet),
+            ),
+        );
 }
 
 // Listen for interaction events and update the state.
@@ -97,12 +118,13 @@ fn handle_interaction(mut events: MessageReader<InteractionEvent>, mut query: Qu
     }
 }
 
-// Add or remove flickering light based on the fireplace state.
+// Add or remove flickering light based on the switch state, the switch only clicks for changes after startup.
 fn handle_light(
     mut commands: Commands,
     audio_assets: Res<AudioAssets>,
+    audio_config: Res<AudioConfig>,
     sprite_assets: Res<SpriteAssets>,
-    parent_query: Query<(&Children, &State, &mut Sprite), (With<Switch>, With<InRange>, Changed<State>)>,
+    parent_query: Query<(&Children, Ref<State>, &mut Sprite), (With<Switch>, Changed<State>)>,
     mut light_query: Query<(Entity, &mut PointLight2d, Option<&AtticLight>, Option<&XmasLight>)>,
 ) {
     let mut rng = rand::rng();
@@ -202,26 +224,25 @@ fn handle_light(
             }
         }
 
-        match state {
-            State::On => {
-                commands.spawn((
-                    AudioPlayer::new(audio_assets.on.clone()),
-                    PlaybackSettings::DESPAWN.with_volume(Volume::Linear(SWITCH_VOLUME)),
-                ));
-            }
-
-            State::Off => {
-                commands.spawn((
-                    AudioPlayer::new(audio_assets.off.clone()),
-                    PlaybackSettings::DESPAWN.with_volume(Volume::Linear(SWITCH_VOLUME)),
-                ));
-            }
+        if state.is_added() {
+            continue;
         }
+
+        let sound = match *state {
+            State::On => audio_assets.on.clone(),
+            State::Off => audio_assets.off.clone(),
+        };
+        play_one_shot(&mut commands, &audio_config, sound, audio_config.light_switch);
     }
 }
 
 // Attic light initialization.
-fn init(mut commands: Commands, asset_server: Res<AssetServer>) {
+fn init(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    initial_states: Res<InitialStates>,
+    light_string: Res<XmasLightString>,
+) {
     // Load the sprite sheets.
     let sprites = SpriteAssets {
         switch_on: asset_server.load("house/light_switch_on.png"),
@@ -238,16 +259,21 @@ fn init(mut commands: Commands, asset_server: Res<AssetServer>) {
     };
     commands.insert_resource(audio);
 
-    // Parent position is the hidden switch.
+    // Parent position is the hidden switch, the lights follow its starting state once spawned.
+    // NOTE: the switch is on the chair side of the fireplace so the lit fire never blocks the way to it.
+    let state = initial_states.get(INTERACTABLE_ID);
     let parent = commands
         .spawn((
             Switch,
-            State::Off,
+            state,
             Sprite {
-                image: sprites.switch_off,
+                image: match state {
+                    State::On => sprites.switch_on.clone(),
+                    State::Off => sprites.switch_off.clone(),
+                },
                 ..default()
             },
-            Transform::from_xyz(148.0, -50.0, 5.0),
+            Transform::from_xyz(84.0, -50.0, 5.0),
             Interactable {
                 id: INTERACTABLE_ID.to_string(),
                 height: 4.0,
@@ -257,11 +283,11 @@ fn init(mut commands: Commands, asset_server: Res<AssetServer>) {
         ))
         .id();
 
-    // Spawn light, Local offset from switch (-21, 110, 0) → Global position (128, 60, 5)
+    // Spawn light, Local offset from switch (44, 110, 0) → Global position (128, 60, 5)
     let light_id = commands
         .spawn((
             AtticLight,
-            Transform::from_xyz(-20.0, 110.0, 0.0),
+            Transform::from_xyz(44.0, 110.0, 0.0),
             PointLight2d {
                 color: ATTIC_LIGHT_COLORS[0],
                 intensity: 0.0,
@@ -273,10 +299,9 @@ fn init(mut commands: Commands, asset_server: Res<AssetServer>) {
         .id();
     commands.entity(parent).add_child(light_id);
 
-    let x_offset: f32 = -228.0;
-    let y: f32 = 55.0;
-    for point in 0..40i16 {
-        let x = f32::from(point).mul_add(7.0, x_offset);
+    let y = light_string.y;
+    for point in 0..light_string.bulb_count {
+        let x = f32::from(point).mul_add(light_string.spacing, light_string.start_x);
 
         match point % 3 {
             0 => {
@@ -344,3 +369,68 @@ fn init(mut commands: Commands, asset_server: Res<AssetServer>) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Headless app with the switch and its lights spawned off.
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<AudioSource>()
+            .init_asset::<Image>()
+            .add_message::<InteractionEvent>()
+            .init_resource::<AudioConfig>()
+            .init_resource::<InitialStates>()
+            .init_resource::<XmasLightString>()
+            .add_systems(Startup, init)
+            .add_systems(Update, (handle_interaction, handle_light).chain());
+        app.update();
+        app
+    }
+
+    fn flip_switch(app: &mut App) {
+        app.world_mut().write_message(InteractionEvent {
+            id: INTERACTABLE_ID.to_string(),
+        });
+        app.update();
+    }
+
+    fn flickering_lights(app: &mut App) -> usize {
+        app.world_mut().query::<&FlickeringLight>().iter(app.world()).count()
+    }
+
+    #[test]
+    fn every_light_flickers_while_the_switch_is_on() {
+        let mut app = test_app();
+        let bulbs = usize::from(XmasLightString::default().bulb_count);
+        assert_eq!(flickering_lights(&mut app), 0);
+
+        flip_switch(&mut app);
+        // The Xmas lights and the attic light.
+        assert_eq!(flickering_lights(&mut app), bulbs + 1);
+
+        flip_switch(&mut app);
+        assert_eq!(flickering_lights(&mut app), 0);
+    }
+
+    #[test]
+    fn attic_light_follows_the_switch() {
+        let mut app = test_app();
+        let attic_light = app
+            .world_mut()
+            .query_filtered::<Entity, With<AtticLight>>()
+            .single(app.world())
+            .unwrap();
+
+        flip_switch(&mut app);
+        assert!(app.world().get::<FlickeringLight>(attic_light).is_some());
+        // Stand in for the flicker lighting it up.
+        app.world_mut().get_mut::<PointLight2d>(attic_light).unwrap().intensity = 1.0;
+
+        flip_switch(&mut app);
+        assert!(app.world().get::<FlickeringLight>(attic_light).is_none());
+        assert_eq!(app.world().get::<PointLight2d>(attic_light).unwrap().intensity, 0.0);
+    }
+}
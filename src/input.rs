@@ -1,7 +1,12 @@
 use bevy::input::touch::Touches;
 use bevy::prelude::*;
 
-use crate::interaction::{Highlight, Interactable, aabb_overlap};
+use crate::{
+    camera::MainCamera,
+    fireplace, house_lights,
+    interaction::{Highlight, HighlightTheme, Interactable, InteractionEvent, aabb_overlap},
+    replay, stereo, tree,
+};
 
 // Click component for click feedback sprite.
 #[derive(Component)]
@@ -17,19 +22,27 @@ struct InputWorldPositions {
     positions: Vec<Vec2>,
 }
 
-// Input directions.
+// Facing directions.
 #[derive(Component, Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Direction {
     Left,
     Right,
-    Up,
+}
+
+// What the user wants the man to do.
+#[derive(Clone, Copy, Debug, Default)]
+pub enum Intent {
+    #[default]
+    Idle,
+    Move(Direction),
+    Interact,
+    Navigate(InputTarget),
 }
 
 // Input events generated by the user.
 #[derive(Debug, Default, Message)]
 pub struct InputEvent {
-    pub direction: Option<Direction>,
-    pub target: Option<InputTarget>,
+    pub intent: Intent,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -41,15 +54,22 @@ pub struct InputTarget {
 // Cursor size for aabb detection.
 const CURSOR_SIZE: f32 = 0.1;
 
-// Initialize input systems.
+// Number keys that toggle a prop directly without walking over. 4 is left unbound since the attic light is on the same
+// switch as the house lights, so 5 toggles both.
+const HOTKEYS: [(KeyCode, &str); 4] = [
+    (KeyCode::Digit1, tree::INTERACTABLE_ID),
+    (KeyCode::Digit2, fireplace::INTERACTABLE_ID),
+    (KeyCode::Digit3, stereo::INTERACTABLE_ID),
+    (KeyCode::Digit5, house_lights::INTERACTABLE_ID),
+];
+
+// Initialize input systems, live input is held back during replay playback.
 pub fn add_systems(app: &mut App) {
     app.init_resource::<InputWorldPositions>().add_systems(
         Update,
         (
             handle_fade,
-            handle_keys,
-            handle_mouse_input,
-            handle_touch_input,
+            (handle_hotkeys, handle_keys, handle_mouse_input, handle_touch_input).run_if(not(replay::is_playing)),
             update_input_positions.before(detect_hover),
             detect_hover,
         ),
@@ -68,18 +88,17 @@ fn process_world_click(
             world_pos,
             CURSOR_SIZE,
             CURSOR_SIZE,
-            transform.translation().truncate(),
+            interactable.center(transform),
             interactable.width,
             interactable.height,
         )
     });
 
     input_events.write(InputEvent {
-        target: Some(InputTarget {
+        intent: Intent::Navigate(InputTarget {
             x: world_pos.x,
             action: action,
         }),
-        ..default()
     });
 
     commands.spawn((
@@ -116,23 +135,29 @@ fn handle_fade(
     }
 }
 
+// Send interaction events straight to the prop bound to a pressed number key.
+fn handle_hotkeys(keyboard: Res<ButtonInput<KeyCode>>, mut interaction_events: MessageWriter<InteractionEvent>) {
+    for (key, id) in HOTKEYS {
+        if keyboard.just_pressed(key) {
+            interaction_events.write(InteractionEvent { id: id.to_string() });
+        }
+    }
+}
+
 // Handle key input and send events.
 fn handle_keys(keyboard: Res<ButtonInput<KeyCode>>, mut input_events: MessageWriter<InputEvent>) {
     // Check for key presses.
     if keyboard.just_pressed(KeyCode::ArrowLeft) {
         input_events.write(InputEvent {
-            direction: Some(Direction::Left),
-            ..default()
+            intent: Intent::Move(Direction::Left),
         });
     } else if keyboard.just_pressed(KeyCode::ArrowRight) {
         input_events.write(InputEvent {
-            direction: Some(Direction::Right),
-            ..default()
+            intent: Intent::Move(Direction::Right),
         });
     } else if keyboard.just_pressed(KeyCode::ArrowUp) {
         input_events.write(InputEvent {
-            direction: Some(Direction::Up),
-            ..default()
+            intent: Intent::Interact,
         });
     }
 
@@ -155,7 +180,7 @@ fn handle_mouse_input(
     mut commands: Commands,
     mouse_input: Res<ButtonInput<MouseButton>>,
     windows: Query<&Window>,
-    camera_query: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
     interactables: Query<(&GlobalTransform, &Interactable)>,
     mut input_events: MessageWriter<InputEvent>,
 ) {
@@ -183,25 +208,28 @@ fn handle_touch_input(
     mut commands: Commands,
     touches: Res<Touches>,
     windows: Query<&Window>,
-    camera_query: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
     interactables: Query<(&GlobalTransform, &Interactable)>,
     mut input_events: MessageWriter<InputEvent>,
 ) {
-    for touch in touches.iter_just_pressed() {
-        // Convert touch position to world coordinates.
-        let Ok(_window) = windows.single() else {
-            continue;
-        };
-        let touch_pos = touch.position();
-        let Ok((camera, camera_transform)) = camera_query.single() else {
-            continue;
-        };
-        let Ok(world_pos) = camera.viewport_to_world_2d(camera_transform, touch_pos) else {
-            continue;
-        };
+    // Only the first new touch is used so multi-touch doesn't send conflicting targets.
+    let Some(touch) = touches.iter_just_pressed().next() else {
+        return;
+    };
 
-        process_world_click(&mut commands, world_pos, &interactables, &mut input_events);
-    }
+    // Convert touch position to world coordinates.
+    let Ok(_window) = windows.single() else {
+        return;
+    };
+    let touch_pos = touch.position();
+    let Ok((camera, camera_transform)) = camera_query.single() else {
+        return;
+    };
+    let Ok(world_pos) = camera.viewport_to_world_2d(camera_transform, touch_pos) else {
+        return;
+    };
+
+    process_world_click(&mut commands, world_pos, &interactables, &mut input_events);
 }
 
 // Update the input world positions.
@@ -209,7 +237,7 @@ fn update_input_positions(
     mut inputs: ResMut<InputWorldPositions>,
     touches: Res<Touches>,
     windows: Query<&Window>,
-    camera_query: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
 ) {
     inputs.positions.clear();
 
@@ -241,6 +269,7 @@ fn detect_hover(
     time: Res<Time>,
     mut commands: Commands,
     inputs: Res<InputWorldPositions>,
+    theme: Res<HighlightTheme>,
     interactables: Query<(Entity, &GlobalTransform, &Interactable)>,
     highlighted: Query<&Highlight>,
 ) {
@@ -250,7 +279,7 @@ fn detect_hover(
                 input_pos,
                 CURSOR_SIZE,
                 CURSOR_SIZE,
-                transform.translation().truncate(),
+                interactable.center(transform),
                 interactable.width,
                 interactable.height,
             )
@@ -264,6 +293,7 @@ fn detect_hover(
                 commands.entity(entity).insert(Highlight {
                     elapsed_offset: time.elapsed_secs(),
                     size: Vec2::new(interactable.sprite_width, interactable.sprite_height),
+                    color: theme.hover_color,
                 });
             }
 
@@ -275,3 +305,53 @@ fn detect_hover(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Interact intents sent so far.
+    #[derive(Default, Resource)]
+    struct Interacts(usize);
+
+    fn count_interacts(mut events: MessageReader<InputEvent>, mut interacts: ResMut<Interacts>) {
+        interacts.0 += events
+            .read()
+            .filter(|event| matches!(event.intent, Intent::Interact))
+            .count();
+    }
+
+    // Step a frame, clearing the just pressed and released keys afterwards like the input plugin does.
+    fn step(app: &mut App) {
+        app.update();
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().clear();
+    }
+
+    #[test]
+    fn holding_interact_sends_one_interact_per_press() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_message::<InputEvent>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<Interacts>()
+            .add_systems(Update, (handle_keys, count_interacts).chain());
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::ArrowUp);
+        for _ in 0..10 {
+            step(&mut app);
+        }
+        assert_eq!(app.world().resource::<Interacts>().0, 1);
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .release(KeyCode::ArrowUp);
+        step(&mut app);
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::ArrowUp);
+        step(&mut app);
+        assert_eq!(app.world().resource::<Interacts>().0, 2);
+    }
+}
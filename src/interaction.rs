@@ -1,20 +1,86 @@
 use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
 
-// Added to Interactable entities when they should be highlighted.
+use crate::{attract::Attract, input::Direction};
+
+#[derive(Clone, Resource)]
+struct SpriteAssets {
+    prompt: Handle<Image>,
+}
+
+// How successful interactions are acknowledged on the prop, the pulse and icon are gentle alternatives to the bright
+// flash for photosensitive viewers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Resource)]
+pub enum FeedbackStyle {
+    Flash,
+    #[default]
+    Pulse,
+    Icon,
+    None,
+}
+
+// Added to an Interactable while its interaction is being acknowledged with a flash or pulse, the size is the sprite
+// size the pulse scales from and the color and custom size are put back once it's done.
+#[derive(Component)]
+struct Acknowledgement {
+    timer: Timer,
+    size: Vec2,
+    color: Color,
+    custom_size: Option<Vec2>,
+}
+
+// Icon rising and fading above an Interactable to acknowledge its interaction.
+#[derive(Component)]
+struct AcknowledgementIcon(Timer);
+
+// Added to Interactable entities when they should be highlighted, the color is used for the outline.
 #[derive(Component)]
 pub struct Highlight {
     pub elapsed_offset: f32,
     pub size: Vec2,
+    pub color: Color,
 }
 
-// Add to entities that can initiate interactions.
+// Outline colors for each kind of highlight, outlines are only drawn when enabled.
+// NOTE: nothing takes keyboard focus yet, focus_color is there for when something does.
+#[derive(Clone, Resource)]
+pub struct HighlightTheme {
+    pub hover_color: Color,
+    pub focus_color: Color,
+    pub in_range_color: Color,
+    pub line_width: f32,
+    pub outline: bool,
+}
+
+impl Default for HighlightTheme {
+    fn default() -> Self {
+        Self {
+            hover_color: Color::srgb(1.0, 0.85, 0.3),
+            focus_color: Color::srgb(0.4, 0.8, 1.0),
+            in_range_color: Color::srgb(0.5, 1.0, 0.5),
+            line_width: 1.0,
+            outline: false,
+        }
+    }
+}
+
+// Add to entities that can initiate interactions, the box is centered offset from the transform.
 #[derive(Component)]
 pub struct Interactor {
     pub width: f32,
     pub height: f32,
+    pub offset: Vec2,
 }
 
-// Add to entities that can be interacted with.
+impl Interactor {
+    // World position of the box center.
+    pub fn center(&self, transform: &GlobalTransform) -> Vec2 {
+        transform.translation().truncate() + self.offset
+    }
+}
+
+// Add to entities that can be interacted with, require_facing limits it to Interactors facing that direction and
+// higher priorities win when several overlap. The box is centered offset from the transform, the sprite box isn't.
 #[derive(Component, Default)]
 pub struct Interactable {
     pub id: String,
@@ -23,6 +89,38 @@ pub struct Interactable {
     pub sprite_height: f32,
     pub sprite_width: f32,
     pub highlighted: bool,
+    pub require_facing: Option<Direction>,
+    pub priority: i32,
+    pub offset: Vec2,
+}
+
+impl Interactable {
+    // World position of the box center.
+    pub fn center(&self, transform: &GlobalTransform) -> Vec2 {
+        transform.translation().truncate() + self.offset
+    }
+}
+
+// Interactable ids of props left out of the scene, must be inserted before the plugin is added.
+#[derive(Clone, Default, Resource)]
+pub struct DisabledProps(pub HashSet<String>);
+
+impl DisabledProps {
+    pub fn contains(&self, id: &str) -> bool {
+        self.0.contains(id)
+    }
+}
+
+// Starting state of each prop by interactable id, props that aren't listed start off.
+// NOTE: there's no scene persistence to load these from yet, so named save slots (scene_<slot>.json) have
+// nothing to extend. Themed variants can set their starting states here until persistence lands.
+#[derive(Clone, Default, Resource)]
+pub struct InitialStates(pub HashMap<String, State>);
+
+impl InitialStates {
+    pub fn get(&self, id: &str) -> State {
+        self.0.get(id).copied().unwrap_or(State::Off)
+    }
 }
 
 // Added to Interactor entities when they're in range of an Interactable.
@@ -31,29 +129,80 @@ pub struct InRange {
     pub id: String,
 }
 
+// Scales the Interactor bounds when checking for overlaps, larger values let interactions happen from further away.
+#[derive(Clone, Copy, Resource)]
+pub struct InteractionReach(pub f32);
+
+impl Default for InteractionReach {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
 // Message sent when an interaction is triggered.
 #[derive(Message)]
 pub struct InteractionEvent {
     pub id: String,
 }
 
+// Message sent when an Interactable comes in range.
+#[derive(Message)]
+pub struct RangeEnterEvent {
+    pub id: String,
+}
+
+// Message sent when an Interactable leaves range.
+#[derive(Message)]
+pub struct RangeExitEvent {
+    pub id: String,
+}
+
+// Prompt icon displayed above an Interactable while it's in range.
+#[derive(Component)]
+struct Prompt;
+
 #[derive(Clone, Component, Copy, PartialEq, Eq)]
 pub enum State {
     Off,
     On,
 }
 
+// Distance between the top of an Interactable and its prompt icon.
+const PROMPT_OFFSET: f32 = 5.0;
+
+// Length of the interaction acknowledgement, the peak scale change of the pulse, the peak brightness of the flash, and
+// how far the icon rises.
+const ACKNOWLEDGEMENT_SECONDS: f32 = 0.4;
+const ACKNOWLEDGEMENT_PULSE: f32 = 0.08;
+const ACKNOWLEDGEMENT_FLASH: f32 = 2.0;
+const ACKNOWLEDGEMENT_RISE: f32 = 6.0;
+
 // Add the interaction systems.
 pub fn add_systems(app: &mut App) {
-    app.add_message::<InteractionEvent>().add_systems(
-        Update,
-        (
-            detect_overlaps,
-            handle_highlight,
-            handle_highlight_reset,
-            handle_highlight_state_change,
-        ),
-    );
+    app.add_message::<InteractionEvent>()
+        .add_message::<RangeEnterEvent>()
+        .add_message::<RangeExitEvent>()
+        .init_resource::<FeedbackStyle>()
+        .init_resource::<HighlightTheme>()
+        .init_resource::<InitialStates>()
+        .init_resource::<InteractionReach>()
+        .add_systems(Startup, init)
+        .add_systems(PostStartup, validate_ids)
+        .add_systems(
+            Update,
+            (
+                detect_overlaps,
+                handle_acknowledgement.after(handle_highlight),
+                handle_acknowledgement_icons,
+                handle_feedback,
+                handle_highlight,
+                handle_highlight_reset,
+                handle_highlight_state_change,
+                handle_outline,
+                handle_prompt_add,
+                handle_prompt_remove,
+            ),
+        );
 }
 
 // Axis-Aligned Bounding Box overlap detection.
@@ -76,59 +225,215 @@ pub fn aabb_overlap(pos_1: Vec2, width_1: f32, height_1: f32, pos_2: Vec2, width
     !(right_1 < left_2 || left_1 > right_2 || top_1 < bottom_2 || bottom_1 > top_2)
 }
 
-// Detects overlaps between Interactors and Interactables.
+// Detects overlaps between Interactors and Interactables, only the highest priority overlapping Interactable is in
+// range with ties going to the nearest.
+// NOTE: Interactables requiring a facing are skipped unless the Interactor has a matching Direction.
+#[allow(clippy::too_many_arguments)]
 fn detect_overlaps(
     time: Res<Time>,
     mut commands: Commands,
-    mut interactables: Query<(Entity, &State, &GlobalTransform, &mut Interactable)>,
-    interactors: Query<(&GlobalTransform, &Interactor)>,
+    reach: Res<InteractionReach>,
+    theme: Res<HighlightTheme>,
+    interactables: Query<(Entity, &State, &GlobalTransform, &Interactable)>,
+    interactors: Query<(&GlobalTransform, &Interactor, Option<&Direction>)>,
     in_range: Query<(Entity, &InRange)>,
+    mut enter_events: MessageWriter<RangeEnterEvent>,
+    mut exit_events: MessageWriter<RangeExitEvent>,
 ) {
-    for (interactable_entity, interactable_state, interactable_transform, interactable) in &mut interactables {
-        for (interactor_transform, interactor) in &interactors {
-            let overlaping = aabb_overlap(
-                interactor_transform.translation().truncate(),
-                interactor.width,
-                interactor.height,
-                interactable_transform.translation().truncate(),
-                interactable.width,
-                interactable.height,
+    // Find the nearest overlapping interactable for each interactor.
+    let mut nearest: Vec<Entity> = Vec::new();
+    for (interactor_transform, interactor, facing) in &interactors {
+        let interactor_position = interactor.center(interactor_transform);
+
+        let closest = interactables
+            .iter()
+            .filter(|(_, _, _, interactable)| {
+                interactable
+                    .require_facing
+                    .is_none_or(|required| facing == Some(&required))
+            })
+            .filter(|(_, _, interactable_transform, interactable)| {
+                aabb_overlap(
+                    interactor_position,
+                    interactor.width * reach.0,
+                    interactor.height * reach.0,
+                    interactable.center(interactable_transform),
+                    interactable.width,
+                    interactable.height,
+                )
+            })
+            .min_by(
+                |(_, _, transform_1, interactable_1), (_, _, transform_2, interactable_2)| {
+                    let distance_1 = interactable_1.center(transform_1).distance(interactor_position);
+                    let distance_2 = interactable_2.center(transform_2).distance(interactor_position);
+                    interactable_2
+                        .priority
+                        .cmp(&interactable_1.priority)
+                        .then(distance_1.total_cmp(&distance_2))
+                },
             );
 
-            // Update InRange component based on overlap.
-            let currently_in_range = in_range
-                .iter()
-                .find(|(e, _)| *e == interactable_entity)
-                .map(|(_, r)| r.id.clone());
-
-            match (currently_in_range, overlaping) {
-                // New entity entered in-range.
-                (None, true) => {
-                    commands.entity(interactable_entity).insert(InRange {
-                        id: interactable.id.clone(),
+        if let Some((entity, ..)) = closest {
+            nearest.push(entity);
+        }
+    }
+
+    for (interactable_entity, interactable_state, _, interactable) in &interactables {
+        let overlaping = nearest.contains(&interactable_entity);
+
+        // Update InRange component based on overlap.
+        let currently_in_range = in_range
+            .iter()
+            .find(|(e, _)| *e == interactable_entity)
+            .map(|(_, r)| r.id.clone());
+
+        match (currently_in_range, overlaping) {
+            // New entity entered in-range.
+            (None, true) => {
+                commands.entity(interactable_entity).insert(InRange {
+                    id: interactable.id.clone(),
+                });
+                enter_events.write(RangeEnterEvent {
+                    id: interactable.id.clone(),
+                });
+                if !interactable.highlighted && *interactable_state == State::Off {
+                    commands.entity(interactable_entity).insert(Highlight {
+                        elapsed_offset: time.elapsed_secs(),
+                        size: Vec2::new(interactable.sprite_width, interactable.sprite_height),
+                        color: theme.in_range_color,
                     });
-                    if !interactable.highlighted && *interactable_state == State::Off {
-                        commands.entity(interactable_entity).insert(Highlight {
-                            elapsed_offset: time.elapsed_secs(),
-                            size: Vec2::new(interactable.sprite_width, interactable.sprite_height),
-                        });
-                    }
                 }
+            }
+
+            // Entity in-range changed.
+            (Some(current_id), true) if current_id != interactable.id => {
+                commands.entity(interactable_entity).insert(InRange {
+                    id: interactable.id.clone(),
+                });
+                exit_events.write(RangeExitEvent { id: current_id });
+                enter_events.write(RangeEnterEvent {
+                    id: interactable.id.clone(),
+                });
+            }
+
+            // Entity left in-range.
+            (Some(current_id), false) => {
+                exit_events.write(RangeExitEvent { id: current_id });
+                commands.entity(interactable_entity).remove::<InRange>();
+                commands.entity(interactable_entity).remove::<Highlight>();
+            }
+
+            _ => {}
+        }
+    }
+}
+
+// Pulse or flash acknowledged Interactables, restoring them once done.
+// NOTE: the pulse scales the sprite rather than the transform so child lights stay put, sprites without a size set on
+// their Interactable don't pulse.
+fn handle_acknowledgement(
+    mut commands: Commands,
+    time: Res<Time>,
+    style: Res<FeedbackStyle>,
+    mut query: Query<(Entity, &mut Acknowledgement, &mut Sprite)>,
+) {
+    for (entity, mut acknowledgement, mut sprite) in &mut query {
+        acknowledgement.timer.tick(time.delta());
+        if acknowledgement.timer.just_finished() {
+            sprite.color = acknowledgement.color;
+            sprite.custom_size = acknowledgement.custom_size;
+            commands.entity(entity).remove::<Acknowledgement>();
+            continue;
+        }
+
+        let wave = (acknowledgement.timer.fraction() * std::f32::consts::PI).sin();
+        match *style {
+            FeedbackStyle::Flash => {
+                let brightness = wave.mul_add(ACKNOWLEDGEMENT_FLASH - 1.0, 1.0);
+                sprite.color = Color::srgb(brightness, brightness, brightness);
+            }
+            FeedbackStyle::Pulse => {
+                if acknowledgement.size != Vec2::ZERO {
+                    sprite.custom_size = Some(acknowledgement.size * ACKNOWLEDGEMENT_PULSE.mul_add(wave, 1.0));
+                }
+            }
+            FeedbackStyle::Icon | FeedbackStyle::None => {}
+        }
+    }
+}
+
+// Rise and fade the acknowledgement icons, despawning them once done.
+fn handle_acknowledgement_icons(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut AcknowledgementIcon, &mut Transform, &mut Sprite)>,
+) {
+    for (entity, mut icon, mut transform, mut sprite) in &mut query {
+        icon.0.tick(time.delta());
+        if icon.0.just_finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        transform.translation.y += ACKNOWLEDGEMENT_RISE / ACKNOWLEDGEMENT_SECONDS * time.delta_secs();
+        sprite.color.set_alpha(1.0 - icon.0.fraction());
+    }
+}
 
-                // Entity in-range changed.
-                (Some(current_id), true) if current_id != interactable.id => {
-                    commands.entity(interactable_entity).insert(InRange {
-                        id: interactable.id.clone(),
+// Acknowledge interactions by the man on the Interactable in range in the configured feedback style, hotkeys and the
+// attract script aren't acknowledged.
+fn handle_feedback(
+    mut commands: Commands,
+    style: Res<FeedbackStyle>,
+    sprite_assets: Res<SpriteAssets>,
+    attract: Option<Res<Attract>>,
+    mut events: MessageReader<InteractionEvent>,
+    query: Query<(Entity, &Interactable, Option<&Sprite>, Option<&Acknowledgement>), With<InRange>>,
+) {
+    let attracting = attract.is_some_and(|attract| attract.active);
+
+    for event in events.read() {
+        if attracting {
+            continue;
+        }
+
+        for (entity, interactable, sprite, acknowledgement) in &query {
+            if interactable.id != event.id {
+                continue;
+            }
+
+            let timer = Timer::from_seconds(ACKNOWLEDGEMENT_SECONDS, TimerMode::Once);
+            match *style {
+                FeedbackStyle::Flash | FeedbackStyle::Pulse => {
+                    // Keep what the sprite looked like before an acknowledgement that's still running.
+                    let (color, custom_size) = match (acknowledgement, sprite) {
+                        (Some(acknowledgement), _) => (acknowledgement.color, acknowledgement.custom_size),
+                        (None, Some(sprite)) => (sprite.color, sprite.custom_size),
+                        (None, None) => (Color::WHITE, None),
+                    };
+                    commands.entity(entity).insert(Acknowledgement {
+                        timer,
+                        size: Vec2::new(interactable.sprite_width, interactable.sprite_height),
+                        color,
+                        custom_size,
                     });
                 }
 
-                // Entity left in-range.
-                (Some(_), false) => {
-                    commands.entity(interactable_entity).remove::<InRange>();
-                    commands.entity(interactable_entity).remove::<Highlight>();
+                FeedbackStyle::Icon => {
+                    let icon = commands
+                        .spawn((
+                            Sprite {
+                                image: sprite_assets.prompt.clone(),
+                                ..default()
+                            },
+                            Transform::from_xyz(0.0, (interactable.height / 2.0) + PROMPT_OFFSET * 2.0, 1.0),
+                            AcknowledgementIcon(timer),
+                        ))
+                        .id();
+                    commands.entity(entity).add_child(icon);
                 }
 
-                _ => {}
+                FeedbackStyle::None => {}
             }
         }
     }
@@ -166,3 +471,270 @@ fn handle_highlight_state_change(
         }
     }
 }
+
+// Outline highlighted sprites in their highlight color when outlines are enabled.
+fn handle_outline(mut gizmos: Gizmos, theme: Res<HighlightTheme>, query: Query<(&GlobalTransform, &Highlight)>) {
+    if !theme.outline {
+        return;
+    }
+
+    for (transform, highlight) in &query {
+        gizmos.rect_2d(
+            Isometry2d::from_translation(transform.translation().truncate()),
+            highlight.size,
+            highlight.color,
+        );
+    }
+}
+
+// Spawn a prompt icon above Interactables when they come in range.
+fn handle_prompt_add(
+    mut commands: Commands,
+    sprite_assets: Res<SpriteAssets>,
+    query: Query<(Entity, &Interactable), Added<InRange>>,
+) {
+    for (entity, interactable) in &query {
+        let prompt = commands
+            .spawn((
+                Sprite {
+                    image: sprite_assets.prompt.clone(),
+                    ..default()
+                },
+                Transform::from_xyz(0.0, (interactable.height / 2.0) + PROMPT_OFFSET, 1.0),
+                Prompt,
+            ))
+            .id();
+
+        commands.entity(entity).add_child(prompt);
+    }
+}
+
+// Despawn the prompt icon when an Interactable leaves range.
+fn handle_prompt_remove(
+    mut commands: Commands,
+    mut removed: RemovedComponents<InRange>,
+    children_query: Query<&Children>,
+    prompt_query: Query<Entity, With<Prompt>>,
+) {
+    for entity in removed.read() {
+        if let Ok(children) = children_query.get(entity) {
+            for child in children.iter() {
+                if prompt_query.contains(child) {
+                    commands.entity(child).despawn();
+                }
+            }
+        }
+    }
+}
+
+// Interaction initialization.
+fn init(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    theme: Res<HighlightTheme>,
+    mut gizmo_config: ResMut<GizmoConfigStore>,
+) {
+    gizmo_config.config_mut::<DefaultGizmoConfigGroup>().0.line.width = theme.line_width;
+
+    commands.insert_resource(SpriteAssets {
+        prompt: asset_server.load("interaction/prompt.png"),
+    });
+}
+
+// Ids used more than once, sorted and listed once each.
+fn duplicate_ids<'a>(ids: impl Iterator<Item = &'a str>) -> Vec<&'a str> {
+    let mut seen = HashSet::new();
+    let mut duplicates: Vec<&str> = ids.filter(|id| !seen.insert(*id)).collect();
+    duplicates.sort_unstable();
+    duplicates.dedup();
+    duplicates
+}
+
+// Log an error listing any ids shared by more than one Interactable, their interactions would cross-fire.
+fn validate_ids(query: Query<&Interactable>) {
+    let duplicates = duplicate_ids(query.iter().map(|interactable| interactable.id.as_str()));
+
+    if !duplicates.is_empty() {
+        error!("interactable ids used more than once: {}", duplicates.join(", "));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Headless app detecting overlaps.
+    fn overlap_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_message::<RangeEnterEvent>()
+            .add_message::<RangeExitEvent>()
+            .init_resource::<HighlightTheme>()
+            .init_resource::<InteractionReach>()
+            .add_systems(Update, detect_overlaps);
+        app
+    }
+
+    // Spawn an Interactable box of the given width centered at x.
+    fn spawn_interactable(app: &mut App, interactable: Interactable, x: f32) -> Entity {
+        app.world_mut()
+            .spawn((State::Off, GlobalTransform::from_xyz(x, 0.0, 0.0), interactable))
+            .id()
+    }
+
+    fn interactable(id: &str, width: f32) -> Interactable {
+        Interactable {
+            id: id.to_string(),
+            width,
+            height: 10.0,
+            ..default()
+        }
+    }
+
+    // Spawn a small Interactor at x.
+    fn spawn_interactor(app: &mut App, x: f32) -> Entity {
+        app.world_mut()
+            .spawn((
+                GlobalTransform::from_xyz(x, 0.0, 0.0),
+                Interactor {
+                    width: 4.0,
+                    height: 10.0,
+                    offset: Vec2::ZERO,
+                },
+            ))
+            .id()
+    }
+
+    fn move_to(app: &mut App, entity: Entity, x: f32) {
+        *app.world_mut().get_mut::<GlobalTransform>(entity).unwrap() = GlobalTransform::from_xyz(x, 0.0, 0.0);
+    }
+
+    fn in_range(app: &App, entity: Entity) -> Option<&str> {
+        app.world().get::<InRange>(entity).map(|in_range| in_range.id.as_str())
+    }
+
+    #[test]
+    fn facing_requirement_limits_the_range() {
+        let mut app = overlap_app();
+        let chair = spawn_interactable(
+            &mut app,
+            Interactable {
+                require_facing: Some(Direction::Right),
+                ..interactable("chair", 20.0)
+            },
+            0.0,
+        );
+        let interactor = spawn_interactor(&mut app, 0.0);
+        app.world_mut().entity_mut(interactor).insert(Direction::Left);
+
+        app.update();
+        assert_eq!(in_range(&app, chair), None);
+
+        app.world_mut().entity_mut(interactor).insert(Direction::Right);
+        app.update();
+        assert_eq!(in_range(&app, chair), Some("chair"));
+    }
+
+    #[test]
+    fn duplicate_ids_are_flagged_once_each() {
+        let ids = ["tree", "chair", "tree", "stereo", "chair", "tree"];
+
+        assert_eq!(duplicate_ids(ids.into_iter()), vec!["chair", "tree"]);
+        assert!(duplicate_ids(["tree", "chair"].into_iter()).is_empty());
+    }
+
+    #[test]
+    fn higher_priority_wins_over_the_nearer_interactable() {
+        let mut app = overlap_app();
+        let fireplace = spawn_interactable(&mut app, interactable("fireplace", 20.0), 0.0);
+        let chair = spawn_interactable(
+            &mut app,
+            Interactable {
+                priority: 1,
+                ..interactable("chair", 20.0)
+            },
+            8.0,
+        );
+        spawn_interactor(&mut app, 1.0);
+
+        app.update();
+        assert_eq!(in_range(&app, chair), Some("chair"));
+        assert_eq!(in_range(&app, fireplace), None);
+    }
+
+    #[test]
+    fn equal_priorities_go_to_the_nearest() {
+        let mut app = overlap_app();
+        let fireplace = spawn_interactable(&mut app, interactable("fireplace", 20.0), 0.0);
+        let chair = spawn_interactable(&mut app, interactable("chair", 20.0), 8.0);
+        spawn_interactor(&mut app, 6.0);
+
+        app.update();
+        assert_eq!(in_range(&app, chair), Some("chair"));
+        assert_eq!(in_range(&app, fireplace), None);
+    }
+
+    #[test]
+    fn in_range_follows_the_interactor_in_and_out() {
+        let mut app = overlap_app();
+        let tree = spawn_interactable(&mut app, interactable("tree", 10.0), 0.0);
+        let interactor = spawn_interactor(&mut app, 50.0);
+
+        app.update();
+        assert_eq!(in_range(&app, tree), None);
+
+        move_to(&mut app, interactor, 2.0);
+        app.update();
+        assert_eq!(in_range(&app, tree), Some("tree"));
+
+        move_to(&mut app, interactor, 50.0);
+        app.update();
+        assert_eq!(in_range(&app, tree), None);
+    }
+
+    #[test]
+    fn in_range_moves_to_the_adjacent_interactable() {
+        let mut app = overlap_app();
+        let tree = spawn_interactable(&mut app, interactable("tree", 10.0), 0.0);
+        let chair = spawn_interactable(&mut app, interactable("chair", 10.0), 12.0);
+        let interactor = spawn_interactor(&mut app, -2.0);
+
+        app.update();
+        assert_eq!(in_range(&app, tree), Some("tree"));
+
+        move_to(&mut app, interactor, 14.0);
+        app.update();
+        assert_eq!(in_range(&app, tree), None);
+        assert_eq!(in_range(&app, chair), Some("chair"));
+    }
+
+    #[test]
+    fn offsets_move_the_boxes_off_the_transforms() {
+        let mut app = overlap_app();
+        let tree = spawn_interactable(&mut app, interactable("tree", 10.0), 0.0);
+        let interactor = app
+            .world_mut()
+            .spawn((
+                GlobalTransform::from_xyz(0.0, 30.0, 0.0),
+                Interactor {
+                    width: 4.0,
+                    height: 10.0,
+                    offset: Vec2::ZERO,
+                },
+            ))
+            .id();
+
+        app.update();
+        assert_eq!(in_range(&app, tree), None);
+
+        // Lowering the interactor box to the tree's reaches it.
+        app.world_mut().get_mut::<Interactor>(interactor).unwrap().offset = Vec2::new(0.0, -30.0);
+        app.update();
+        assert_eq!(in_range(&app, tree), Some("tree"));
+
+        // Raising the tree's box back out of reach.
+        app.world_mut().get_mut::<Interactable>(tree).unwrap().offset = Vec2::new(0.0, 30.0);
+        app.update();
+        assert_eq!(in_range(&app, tree), None);
+    }
+}
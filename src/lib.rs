@@ -0,0 +1,31 @@
+//! Holiday card scene, usable as a library through `HolidayCardPlugin` or run directly with `run_app`.
+
+pub mod animation;
+pub mod app;
+pub mod attract;
+pub mod audio;
+pub mod background;
+pub mod camera;
+pub mod chair;
+pub mod couch;
+pub mod doorbell;
+pub mod fireplace;
+pub mod flickering_light;
+pub mod house;
+pub mod house_lights;
+pub mod input;
+pub mod interaction;
+pub mod noise;
+pub mod replay;
+pub mod sampling;
+pub mod santa;
+pub mod snow;
+pub mod snowman;
+pub mod stereo;
+pub mod theman;
+pub mod tree;
+pub mod y_sort;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use app::generate_thumbnail;
+pub use app::{HolidayCardPlugin, run_app};
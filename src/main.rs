@@ -1,24 +1,5 @@
-//! Animate a sprite in response to a keyboard event.
-
-mod animation;
-mod app;
-mod background;
-mod camera;
-mod chair;
-mod fireplace;
-mod flickering_light;
-mod house;
-mod house_lights;
-mod input;
-mod interaction;
-mod noise;
-mod santa;
-mod snow;
-mod snowman;
-mod stereo;
-mod theman;
-mod tree;
+//! Thin binary wrapper that runs the holiday card through the library, see `HolidayCardPlugin` and `run_app`.
 
 fn main() {
-    app::run_app();
+    holiday_card::run_app();
 }
@@ -21,6 +21,11 @@ const PERMUTATION: [u8; 256] = [
 // Noise generation combining fractal brownian motion and multiple octaves of Perlin noise.
 // NOTE: Each octave has double the frequency and half the amplitude of the previous.
 pub fn generate(x: f32, y: f32, octaves: u32) -> f32 {
+    // No octaves means no noise, avoids dividing by a zero max value.
+    if octaves == 0 {
+        return 0.0;
+    }
+
     let mut total = 0.0;
     let mut frequency = 1.0;
     let mut amplitude = 1.0;
@@ -90,3 +95,72 @@ fn perlin_2d(x: f32, y: f32) -> f32 {
 const fn perm(index: f32) -> u8 {
     PERMUTATION[(index as usize) & 255]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fade_keeps_the_ends_and_midpoint() {
+        assert_eq!(fade(0.0), 0.0);
+        assert_eq!(fade(1.0), 1.0);
+        assert_eq!(fade(0.5), 0.5);
+    }
+
+    #[test]
+    fn lerp_returns_the_ends() {
+        assert_eq!(lerp(0.0, -3.0, 5.0), -3.0);
+        assert_eq!(lerp(1.0, -3.0, 5.0), 5.0);
+    }
+
+    #[test]
+    fn grad_stays_in_range_for_every_bucket() {
+        let corners = [
+            (0.0, 0.0),
+            (1.0, 0.0),
+            (0.0, 1.0),
+            (1.0, 1.0),
+            (-1.0, -1.0),
+            (0.5, -0.25),
+        ];
+
+        for hash in 0..8u8 {
+            for (x, y) in corners {
+                let value = grad(hash, x, y);
+                assert!(
+                    (-2.0..=2.0).contains(&value),
+                    "bucket {hash} gave {value} at ({x}, {y})"
+                );
+                // Only the low three bits pick the gradient.
+                assert_eq!(value, grad(hash + 8, x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn generate_is_deterministic() {
+        for (x, y) in [(0.3, 7.1), (12.5, 0.25), (99.9, 42.0)] {
+            assert_eq!(generate(x, y, 4), generate(x, y, 4));
+        }
+    }
+
+    #[test]
+    fn generate_stays_in_range() {
+        for octaves in [1, 2, 4] {
+            for i in 0..50u8 {
+                for j in 0..50u8 {
+                    let value = generate(f32::from(i) * 0.37, f32::from(j) * 0.41, octaves);
+                    assert!(
+                        (-1.01..=1.01).contains(&value),
+                        "{value} at ({i}, {j}) with {octaves} octaves"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn generate_without_octaves_is_zero() {
+        assert_eq!(generate(3.2, 1.7, 0), 0.0);
+    }
+}
@@ -0,0 +1,318 @@
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use std::{fs, time::Duration};
+
+use crate::{
+    input::{Direction, InputEvent, InputTarget, Intent},
+    theman::{self, TeleportEvent, TheMan},
+};
+
+// Recorded session, an optional start line with where the man stood followed by one line per frame with its time step
+// in nanoseconds, each followed by the intents sent during that frame.
+const REPLAY_PATH: &str = "replay.txt";
+
+#[derive(Default, PartialEq)]
+enum Mode {
+    #[default]
+    Off,
+    Recording,
+    Playing,
+}
+
+// A recorded frame's time step and the intents sent during it.
+#[derive(Debug, Default)]
+struct Frame {
+    delta: Duration,
+    intents: Vec<Intent>,
+}
+
+// Recording and playback progress. Playback steps time by the recorded frame deltas, the time strategy in use before it
+// started is restored once it ends.
+// NOTE: only the man is put back where recording started, props keep their current states so start playback from the
+// same scene the recording started in for an exact replay.
+#[derive(Default, Resource)]
+pub(crate) struct Replay {
+    mode: Mode,
+    start: Option<(Vec2, Direction)>,
+    frames: Vec<Frame>,
+    next: usize,
+    time_strategy: Option<TimeUpdateStrategy>,
+}
+
+// Add the replay systems, playback sends each frame's events before the frame's updates and recording collects them
+// once they're all sent.
+pub fn add_systems(app: &mut App) {
+    app.init_resource::<Replay>()
+        .add_systems(PreUpdate, handle_playback)
+        .add_systems(Update, handle_controls)
+        .add_systems(PostUpdate, handle_recording);
+}
+
+// Run condition that's true during playback, live input is held back so only the recorded events reach the man. Builds
+// without the replay systems, like the web build, never play back.
+pub(crate) fn is_playing(replay: Option<Res<Replay>>) -> bool {
+    replay.is_some_and(|replay| replay.mode == Mode::Playing)
+}
+
+// Parse a recording back into where the man started and its frames.
+fn decode(contents: &str) -> Option<(Option<(Vec2, Direction)>, Vec<Frame>)> {
+    let mut start = None;
+    let mut frames: Vec<Frame> = Vec::new();
+    for line in contents.lines() {
+        let mut parts = line.split_whitespace();
+        let intent = match parts.next()? {
+            "start" => {
+                let position = Vec2::new(parts.next()?.parse().ok()?, parts.next()?.parse().ok()?);
+                let direction = match parts.next()? {
+                    "left" => Direction::Left,
+                    "right" => Direction::Right,
+                    _ => return None,
+                };
+                start = Some((position, direction));
+                continue;
+            }
+
+            "frame" => {
+                frames.push(Frame {
+                    delta: Duration::from_nanos(parts.next()?.parse().ok()?),
+                    intents: Vec::new(),
+                });
+                continue;
+            }
+
+            "idle" => Intent::Idle,
+            "left" => Intent::Move(Direction::Left),
+            "right" => Intent::Move(Direction::Right),
+            "interact" => Intent::Interact,
+            "navigate" => Intent::Navigate(InputTarget {
+                x: parts.next()?.parse().ok()?,
+                action: parts.next()?.parse().ok()?,
+            }),
+            _ => return None,
+        };
+        frames.last_mut()?.intents.push(intent);
+    }
+
+    Some((start, frames))
+}
+
+// Format where the man started and the recorded frames.
+fn encode(start: Option<(Vec2, Direction)>, frames: &[Frame]) -> String {
+    let mut lines = Vec::new();
+    if let Some((position, direction)) = start {
+        let direction = match direction {
+            Direction::Left => "left",
+            Direction::Right => "right",
+        };
+        lines.push(format!("start {} {} {direction}", position.x, position.y));
+    }
+
+    for frame in frames {
+        lines.push(format!("frame {}", frame.delta.as_nanos()));
+        lines.extend(frame.intents.iter().map(|intent| match intent {
+            Intent::Idle => "idle".to_string(),
+            Intent::Move(Direction::Left) => "left".to_string(),
+            Intent::Move(Direction::Right) => "right".to_string(),
+            Intent::Interact => "interact".to_string(),
+            Intent::Navigate(target) => format!("navigate {} {}", target.x, target.action),
+        }));
+    }
+
+    lines.join("\n")
+}
+
+// F5 starts and stops recording, F6 starts and stops playback from where the man stood when recording started.
+fn handle_controls(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut replay: ResMut<Replay>,
+    mut time_strategy: ResMut<TimeUpdateStrategy>,
+    mut teleport_events: MessageWriter<TeleportEvent>,
+    man_query: Query<(&Transform, &Direction), With<TheMan>>,
+) {
+    if keyboard.just_pressed(KeyCode::F5) {
+        match replay.mode {
+            Mode::Recording => {
+                if let Err(error) = fs::write(REPLAY_PATH, encode(replay.start, &replay.frames)) {
+                    error!("failed to save the replay to {REPLAY_PATH}: {error}");
+                }
+                replay.mode = Mode::Off;
+            }
+
+            Mode::Off | Mode::Playing => {
+                stop_playback(&mut replay, &mut time_strategy);
+                replay.start = man_query
+                    .single()
+                    .ok()
+                    .map(|(transform, direction)| (transform.translation.truncate(), *direction));
+                replay.frames.clear();
+                replay.mode = Mode::Recording;
+            }
+        }
+    } else if keyboard.just_pressed(KeyCode::F6) {
+        match replay.mode {
+            Mode::Playing => stop_playback(&mut replay, &mut time_strategy),
+
+            Mode::Off | Mode::Recording => match fs::read_to_string(REPLAY_PATH).map(|contents| decode(&contents)) {
+                Ok(Some((start, frames))) if !frames.is_empty() => {
+                    if let Some((position, direction)) = start {
+                        teleport_events.write(TeleportEvent {
+                            position,
+                            state: theman::State::Idle,
+                            direction,
+                        });
+                    }
+
+                    // The first recorded frame plays next frame, step time by its delta from then on.
+                    let delta = frames[0].delta;
+                    replay.time_strategy = Some(std::mem::replace(
+                        &mut *time_strategy,
+                        TimeUpdateStrategy::ManualDuration(delta),
+                    ));
+                    replay.frames = frames;
+                    replay.next = 0;
+                    replay.mode = Mode::Playing;
+                }
+
+                Ok(_) => error!("the replay in {REPLAY_PATH} is empty or malformed"),
+
+                Err(error) => error!("failed to load the replay from {REPLAY_PATH}: {error}"),
+            },
+        }
+    }
+}
+
+// Send the next recorded frame's intents and step time by the frame after it, stopping after the last one.
+fn handle_playback(
+    mut replay: ResMut<Replay>,
+    mut time_strategy: ResMut<TimeUpdateStrategy>,
+    mut input_events: MessageWriter<InputEvent>,
+) {
+    if replay.mode != Mode::Playing {
+        return;
+    }
+
+    if let Some(frame) = replay.frames.get(replay.next) {
+        input_events.write_batch(frame.intents.iter().map(|&intent| InputEvent { intent }));
+    }
+    replay.next += 1;
+
+    match replay.frames.get(replay.next) {
+        Some(frame) => *time_strategy = TimeUpdateStrategy::ManualDuration(frame.delta),
+        None => stop_playback(&mut replay, &mut time_strategy),
+    }
+}
+
+// Record each frame's time step along with the input events sent during it.
+fn handle_recording(time: Res<Time>, mut replay: ResMut<Replay>, mut events: MessageReader<InputEvent>) {
+    if replay.mode != Mode::Recording {
+        events.clear();
+        return;
+    }
+
+    replay.frames.push(Frame {
+        delta: time.delta(),
+        intents: events.read().map(|event| event.intent).collect(),
+    });
+}
+
+// End playback, handing time back to the strategy it used before.
+fn stop_playback(replay: &mut Replay, time_strategy: &mut TimeUpdateStrategy) {
+    if replay.mode != Mode::Playing {
+        return;
+    }
+
+    if let Some(previous) = replay.time_strategy.take() {
+        *time_strategy = previous;
+    }
+    replay.mode = Mode::Off;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Time steps and intents seen by each frame.
+    #[derive(Default, Resource)]
+    struct Seen(Vec<(Duration, usize)>);
+
+    fn collect(time: Res<Time>, mut events: MessageReader<InputEvent>, mut seen: ResMut<Seen>) {
+        seen.0.push((time.delta(), events.read().count()));
+    }
+
+    #[test]
+    fn recording_round_trips_through_its_text_form() {
+        let start = Some((Vec2::new(-64.0, -56.0), Direction::Left));
+        let frames = vec![
+            Frame {
+                delta: Duration::from_nanos(16_666_667),
+                intents: vec![Intent::Move(Direction::Right)],
+            },
+            Frame {
+                delta: Duration::from_millis(20),
+                intents: Vec::new(),
+            },
+            Frame {
+                delta: Duration::from_millis(17),
+                intents: vec![
+                    Intent::Navigate(InputTarget { x: 12.5, action: true }),
+                    Intent::Interact,
+                ],
+            },
+        ];
+
+        let (decoded_start, decoded_frames) = decode(&encode(start, &frames)).unwrap();
+        assert_eq!(decoded_start, start);
+        assert_eq!(decoded_frames.len(), frames.len());
+        for (decoded, frame) in decoded_frames.iter().zip(&frames) {
+            assert_eq!(decoded.delta, frame.delta);
+            assert_eq!(format!("{:?}", decoded.intents), format!("{:?}", frame.intents));
+        }
+    }
+
+    #[test]
+    fn playback_steps_time_by_the_recorded_frames() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)))
+            .add_message::<InputEvent>()
+            .init_resource::<Replay>()
+            .init_resource::<Seen>()
+            .add_systems(Update, (handle_playback, collect).chain());
+        app.update();
+
+        // Start playback the way the F6 key does.
+        let previous = std::mem::replace(
+            &mut *app.world_mut().resource_mut::<TimeUpdateStrategy>(),
+            TimeUpdateStrategy::ManualDuration(Duration::from_millis(50)),
+        );
+        *app.world_mut().resource_mut::<Replay>() = Replay {
+            mode: Mode::Playing,
+            frames: vec![
+                Frame {
+                    delta: Duration::from_millis(50),
+                    intents: vec![Intent::Move(Direction::Right)],
+                },
+                Frame {
+                    delta: Duration::from_millis(200),
+                    intents: vec![Intent::Interact, Intent::Idle],
+                },
+            ],
+            time_strategy: Some(previous),
+            ..default()
+        };
+        app.world_mut().resource_mut::<Seen>().0.clear();
+
+        for _ in 0..3 {
+            app.update();
+        }
+
+        assert_eq!(
+            app.world().resource::<Seen>().0,
+            vec![
+                (Duration::from_millis(50), 1),
+                (Duration::from_millis(200), 2),
+                (Duration::from_millis(100), 0),
+            ]
+        );
+        assert!(app.world().resource::<Replay>().mode == Mode::Off);
+    }
+}
@@ -0,0 +1,88 @@
+use bevy::{image::ImageSampler, prelude::*};
+use std::collections::HashMap;
+
+// Opt-in linear sampling for character sprites, smoothing sub-pixel motion while the pixel-art stays nearest.
+#[derive(Clone, Default, Resource)]
+pub struct SubPixelRendering {
+    pub enabled: bool,
+}
+
+// Add to sprites whose images should use linear sampling. Each sprite draws from its own linear copies of its images,
+// keyed by the original, so other sprites sharing an image keep it nearest.
+#[derive(Component, Default)]
+pub struct SmoothSampling {
+    copies: HashMap<AssetId<Image>, Handle<Image>>,
+}
+
+// Add the sampling systems.
+pub fn add_systems(app: &mut App) {
+    app.init_resource::<SubPixelRendering>()
+        .add_systems(Update, handle_smooth_sampling);
+}
+
+// Point smooth sprites at a linear copy of their current image, made the first time the sprite shows it. Sprites
+// swapping images are caught on the next frame.
+fn handle_smooth_sampling(mut images: ResMut<Assets<Image>>, mut query: Query<(&mut Sprite, &mut SmoothSampling)>) {
+    for (mut sprite, mut smooth) in &mut query {
+        let id = sprite.image.id();
+        if smooth.copies.values().any(|copy| copy.id() == id) {
+            continue;
+        }
+
+        let copy = match smooth.copies.get(&id) {
+            Some(copy) => copy.clone(),
+            None => {
+                let Some(mut image) = images.get(id).cloned() else {
+                    continue;
+                };
+                image.sampler = ImageSampler::linear();
+                let copy = images.add(image);
+                smooth.copies.insert(id, copy.clone());
+                copy
+            }
+        };
+        sprite.image = copy;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn smooth_sprites_get_their_own_linear_image() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<Assets<Image>>()
+            .add_systems(Update, handle_smooth_sampling);
+
+        let shared = app.world_mut().resource_mut::<Assets<Image>>().add(Image::default());
+        let other = app.world_mut().resource_mut::<Assets<Image>>().add(Image::default());
+        let smooth = app
+            .world_mut()
+            .spawn((Sprite::from_image(shared.clone()), SmoothSampling::default()))
+            .id();
+        let plain = app.world_mut().spawn(Sprite::from_image(shared.clone())).id();
+        app.update();
+
+        let copy = app.world().get::<Sprite>(smooth).unwrap().image.clone();
+        assert_ne!(copy, shared);
+        assert!(matches!(
+            app.world().resource::<Assets<Image>>().get(&copy).unwrap().sampler,
+            ImageSampler::Descriptor(_)
+        ));
+        assert!(matches!(
+            app.world().resource::<Assets<Image>>().get(&shared).unwrap().sampler,
+            ImageSampler::Default
+        ));
+        assert_eq!(app.world().get::<Sprite>(plain).unwrap().image, shared);
+
+        // Swapping to another image and back reuses the first copy.
+        app.world_mut().get_mut::<Sprite>(smooth).unwrap().image = other;
+        app.update();
+        app.world_mut().get_mut::<Sprite>(smooth).unwrap().image = shared;
+        app.update();
+        assert_eq!(app.world().get::<Sprite>(smooth).unwrap().image, copy);
+        assert_eq!(app.world().resource::<Assets<Image>>().len(), 4);
+    }
+}
@@ -19,25 +19,52 @@ pub struct Santa;
 #[derive(Component)]
 pub struct Run;
 
+// Speeds up or slows down Santa's whole visit, every frame duration is divided by the scale.
+#[derive(Clone, Copy, Resource)]
+pub struct SantaSpeedScale(pub f32);
+
+impl Default for SantaSpeedScale {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
 #[derive(Message)]
 pub struct AddPresentsEvent;
 
+#[derive(Message)]
+pub struct PresentsComingEvent;
+
 #[derive(Message)]
 pub struct SantasHereEvent;
 
+// Last frame of the full visit, presents are only left under the tree when the animation finishes here.
+const PRESENT_DELIVERY_FRAME: usize = 27;
+
+// Frame a moment before the delivery where the tree starts shaking in anticipation.
+const PRESENT_ANTICIPATION_FRAME: usize = 24;
+
 // Add the animation systems.
 pub fn add_systems(app: &mut App) {
     app.add_message::<AddPresentsEvent>()
+        .add_message::<PresentsComingEvent>()
         .add_message::<SantasHereEvent>()
+        .init_resource::<SantaSpeedScale>()
         .add_systems(Startup, init)
         .add_systems(Update, (handle_animations, handle_start));
+
+    // Debug builds can summon Santa or leave presents from anywhere.
+    #[cfg(debug_assertions)]
+    app.add_systems(Update, handle_debug_keys);
 }
 
 // Advance animation frames and states.
 fn handle_animations(
     mut commands: Commands,
     time: Res<Time>,
+    speed: Res<SantaSpeedScale>,
     mut presents_events: MessageWriter<AddPresentsEvent>,
+    mut coming_events: MessageWriter<PresentsComingEvent>,
     mut query: Query<(Entity, &mut AnimationConfig, &mut Sprite), (With<Santa>, With<Run>)>,
 ) {
     for (entity, mut config, mut sprite) in &mut query {
@@ -51,23 +78,17 @@ fn handle_animations(
             // Animation plays once and is removed.
             if atlas.index < config.last_index {
                 atlas.index += 1;
+                if atlas.index == PRESENT_ANTICIPATION_FRAME {
+                    coming_events.write(PresentsComingEvent);
+                }
             } else {
-                if atlas.index == 27 {
+                if atlas.index == PRESENT_DELIVERY_FRAME {
                     add_presents = true;
                 }
                 finished = true;
             }
 
-            // Custom frame timing.
-            // NOTE: This is instead of copying redundant frames in the spritesheet.
-            config.frame_timer = match atlas.index {
-                2 => Timer::new(Duration::from_millis(500), TimerMode::Once),
-                3 => Timer::new(Duration::from_millis(750), TimerMode::Once),
-                7 | 12 => Timer::new(Duration::from_secs(2), TimerMode::Once),
-                19..24 => Timer::new(Duration::from_millis(350), TimerMode::Once),
-                27 => Timer::new(Duration::from_millis(250), TimerMode::Once),
-                _ => AnimationConfig::timer_from_fps(config.fps),
-            };
+            config.frame_timer = frame_timer(atlas.index, config.fps, *speed);
         }
 
         if finished {
@@ -81,9 +102,27 @@ fn handle_animations(
     }
 }
 
+// Send SantasHereEvent with the F8 key and AddPresentsEvent with the F9 key.
+#[cfg(debug_assertions)]
+fn handle_debug_keys(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut santa_events: MessageWriter<SantasHereEvent>,
+    mut presents_events: MessageWriter<AddPresentsEvent>,
+) {
+    if keyboard.just_pressed(KeyCode::F8) {
+        santa_events.write(SantasHereEvent);
+    }
+    if keyboard.just_pressed(KeyCode::F9) {
+        presents_events.write(AddPresentsEvent);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 fn handle_start(
     mut commands: Commands,
     sprite_assets: Res<SpriteAssets>,
+    layouts: Res<Assets<TextureAtlasLayout>>,
+    speed: Res<SantaSpeedScale>,
     mut events: MessageReader<SantasHereEvent>,
     mut query: Query<(Entity, &mut AnimationConfig), With<Santa>>,
     tree_query: Query<&Children, With<Tree>>,
@@ -99,8 +138,19 @@ fn handle_start(
             if has_presents {
                 *config = AnimationConfig::new(0, 7, 6);
             } else {
-                *config = AnimationConfig::new(0, 27, 6);
+                *config = AnimationConfig::new(0, PRESENT_DELIVERY_FRAME, 6);
+            }
+            config.frame_timer = frame_timer(0, config.fps, *speed);
+
+            // Skip the visit rather than index outside the sprite sheet.
+            if !layouts
+                .get(&sprite_assets.animation_layout)
+                .is_some_and(|layout| config.fits(layout))
+            {
+                warn!("santa animation range doesn't fit the sprite sheet, skipping");
+                continue;
             }
+
             commands.entity(entity).insert(Sprite {
                 image: sprite_assets.animation_sprite.clone(),
                 texture_atlas: Some(TextureAtlas {
@@ -129,6 +179,134 @@ fn init(
     commands.spawn((
         Transform::from_translation(Vec3::new(-35.0, -56.0, 10.0)),
         Santa,
-        AnimationConfig::new(0, 27, 4),
+        AnimationConfig::new(0, PRESENT_DELIVERY_FRAME, 4),
     ));
 }
+
+// Timer for how long a frame is shown, scaled by the speed.
+// NOTE: custom frame timing is instead of copying redundant frames in the spritesheet.
+fn frame_timer(index: usize, fps: u8, speed: SantaSpeedScale) -> Timer {
+    let duration = match index {
+        2 => Duration::from_millis(500),
+        3 => Duration::from_millis(750),
+        7 | 12 => Duration::from_secs(2),
+        19..24 => Duration::from_millis(350),
+        PRESENT_DELIVERY_FRAME => Duration::from_millis(250),
+        _ => AnimationConfig::timer_from_fps(fps).duration(),
+    };
+
+    Timer::new(duration.div_f32(speed.0.max(0.01)), TimerMode::Once)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::time::TimeUpdateStrategy;
+
+    // Headless app with the visit start and a sprite sheet of the full visit.
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_message::<SantasHereEvent>()
+            .init_resource::<SantaSpeedScale>()
+            .init_resource::<Assets<TextureAtlasLayout>>()
+            .add_systems(Update, handle_start);
+
+        let layout = app
+            .world_mut()
+            .resource_mut::<Assets<TextureAtlasLayout>>()
+            .add(TextureAtlasLayout::from_grid(UVec2::splat(32), 28, 1, None, None));
+        app.insert_resource(SpriteAssets {
+            animation_sprite: Handle::default(),
+            animation_layout: layout,
+        });
+
+        app
+    }
+
+    // Spawn Santa, fire his arrival, and return the animation range of the visit.
+    fn visit(app: &mut App) -> (usize, usize) {
+        let santa = app
+            .world_mut()
+            .spawn((Santa, AnimationConfig::new(0, PRESENT_DELIVERY_FRAME, 4)))
+            .id();
+        app.world_mut().write_message(SantasHereEvent);
+        app.update();
+
+        assert!(app.world().get::<Run>(santa).is_some());
+        let config = app.world().get::<AnimationConfig>(santa).unwrap();
+        (config.first_index, config.last_index)
+    }
+
+    // Presents deliveries written so far.
+    #[derive(Default, Resource)]
+    struct Deliveries(usize);
+
+    fn count_deliveries(mut events: MessageReader<AddPresentsEvent>, mut deliveries: ResMut<Deliveries>) {
+        deliveries.0 += events.read().count();
+    }
+
+    // Play a whole visit through quickly, returning how many times presents were delivered.
+    fn deliveries_after_visit(app: &mut App) -> usize {
+        app.insert_resource(SantaSpeedScale(100.0))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)))
+            .add_message::<AddPresentsEvent>()
+            .add_message::<PresentsComingEvent>()
+            .init_resource::<Deliveries>()
+            .add_systems(
+                Update,
+                (handle_animations.after(handle_start), count_deliveries).chain(),
+            );
+
+        visit(app);
+        for _ in 0..100 {
+            app.update();
+        }
+        app.world().resource::<Deliveries>().0
+    }
+
+    #[test]
+    fn santa_makes_the_short_visit_when_presents_are_under_the_tree() {
+        let mut app = test_app();
+        app.world_mut().spawn(Tree).with_child(Presents);
+
+        assert_eq!(visit(&mut app), (0, 7));
+    }
+
+    #[test]
+    fn santa_makes_the_full_visit_without_presents() {
+        let mut app = test_app();
+        app.world_mut().spawn(Tree);
+
+        assert_eq!(visit(&mut app), (0, PRESENT_DELIVERY_FRAME));
+    }
+
+    #[test]
+    fn full_visit_delivers_presents_once() {
+        let mut app = test_app();
+        app.world_mut().spawn(Tree);
+
+        assert_eq!(deliveries_after_visit(&mut app), 1);
+    }
+
+    #[test]
+    fn short_visit_delivers_no_presents() {
+        let mut app = test_app();
+        app.world_mut().spawn(Tree).with_child(Presents);
+
+        assert_eq!(deliveries_after_visit(&mut app), 0);
+    }
+
+    #[test]
+    fn double_speed_halves_every_frame() {
+        for index in 0..=PRESENT_DELIVERY_FRAME {
+            let normal = frame_timer(index, 6, SantaSpeedScale(1.0)).duration();
+            let fast = frame_timer(index, 6, SantaSpeedScale(2.0)).duration();
+
+            assert!(
+                (fast.as_secs_f32() - normal.as_secs_f32() / 2.0).abs() < 1e-6,
+                "frame {index}"
+            );
+        }
+    }
+}
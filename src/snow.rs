@@ -1,11 +1,15 @@
 use bevy::prelude::*;
 use rand::Rng;
 
+use crate::{interaction::State, stereo::Stereo};
+
+// Falling particle drifting side to side, a negative fall speed rises instead. Only the snow is recycled once it's
+// below the screen, other particles like the chimney smoke are despawned by whatever spawned them.
 #[derive(Component)]
-struct SnowParticle {
-    fall_speed: f32,
-    drift_speed: f32,
-    drift_phase: f32,
+pub(crate) struct SnowParticle {
+    pub(crate) fall_speed: f32,
+    pub(crate) drift_speed: f32,
+    pub(crate) drift_phase: f32,
 }
 
 #[derive(Component)]
@@ -14,6 +18,79 @@ struct Snow;
 #[derive(Component)]
 struct Respawn;
 
+// Extra particle that only falls while the snow is boosted past its threshold.
+#[derive(Component)]
+struct BoostParticle {
+    threshold: f32,
+}
+
+// Extra snow while the stereo plays, ramping in and out over ramp_seconds.
+#[derive(Clone, Resource)]
+pub struct SnowBoost {
+    pub particles: usize,
+    pub ramp_seconds: f32,
+}
+
+impl Default for SnowBoost {
+    fn default() -> Self {
+        Self {
+            particles: 350,
+            ramp_seconds: 4.0,
+        }
+    }
+}
+
+// Optional intro where the sky starts clear and the snow begins falling after the delay, the density ramping up to
+// the full particle count over ramp_seconds. The ground snow waits for the same delay before it starts rising.
+#[derive(Clone, Resource)]
+pub struct FirstSnow {
+    pub enabled: bool,
+    pub delay: f32,
+    pub ramp_seconds: f32,
+}
+
+impl Default for FirstSnow {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            delay: 4.0,
+            ramp_seconds: 6.0,
+        }
+    }
+}
+
+impl FirstSnow {
+    // Whether the snow has started at the elapsed time, always true while the intro is disabled.
+    pub fn started(&self, elapsed: f32) -> bool {
+        !self.enabled || elapsed >= self.delay
+    }
+
+    // Fraction of the particles released at the elapsed time, between 0 and 1.
+    fn density(&self, elapsed: f32) -> f32 {
+        if !self.started(elapsed) {
+            return 0.0;
+        }
+        ((elapsed - self.delay) / self.ramp_seconds.max(f32::EPSILON)).clamp(0.0, 1.0)
+    }
+}
+
+// Particle held hidden above the screen until the intro density passes its threshold.
+#[derive(Component)]
+struct IntroParticle {
+    threshold: f32,
+}
+
+// Current boost level between 0 and 1.
+#[derive(Default, Resource)]
+pub(crate) struct SnowIntensity(pub(crate) f32);
+
+impl SnowIntensity {
+    // How heavily it's snowing relative to the unboosted snow, from the share of boost particles falling.
+    pub(crate) fn snowfall(&self, boost: &SnowBoost) -> f32 {
+        (self.0 * boost.particles as f32).mul_add(1.0 / PARTICLE_COUNT as f32, 1.0)
+    }
+}
+
 const PARTICLE_COUNT: usize = 700;
 
 const SPAWN_Y: f32 = 100.0;
@@ -32,17 +109,45 @@ const OPACITY_MAX: f32 = 1.0;
 
 // Add the snow systems.
 pub fn add_systems(app: &mut App) {
-    app.add_systems(Startup, init)
-        .add_systems(Update, (handle_snow, handle_snow_respawn));
+    app.init_resource::<FirstSnow>()
+        .init_resource::<SnowBoost>()
+        .init_resource::<SnowIntensity>()
+        .add_systems(Startup, init)
+        .add_systems(
+            Update,
+            (
+                handle_first_snow,
+                handle_snow,
+                handle_snow_intensity,
+                handle_snow_respawn,
+            ),
+        );
 }
 
-// Handle snow particle movement with vertical falling and horizontal wind drift and mark particles that are too low.
+// Release held intro particles as the density ramps up.
+fn handle_first_snow(
+    mut commands: Commands,
+    time: Res<Time>,
+    first_snow: Res<FirstSnow>,
+    mut query: Query<(Entity, &IntroParticle, &mut Visibility)>,
+) {
+    let density = first_snow.density(time.elapsed_secs());
+
+    for (entity, intro_particle, mut visibility) in &mut query {
+        if density > intro_particle.threshold {
+            *visibility = Visibility::Inherited;
+            commands.entity(entity).remove::<IntroParticle>();
+        }
+    }
+}
+
+// Handle particle movement with vertical falling and horizontal wind drift and mark snow that's too low.
 fn handle_snow(
     mut commands: Commands,
     time: Res<Time>,
-    mut query: Query<(Entity, &mut Transform, &SnowParticle), (With<Snow>, Without<Respawn>)>,
+    mut query: Query<(Entity, &mut Transform, &SnowParticle, Has<Snow>), (Without<Respawn>, Without<IntroParticle>)>,
 ) {
-    for (entity, mut transform, particle) in &mut query {
+    for (entity, mut transform, particle, snow) in &mut query {
         let delta = time.delta_secs();
 
         // Vertical fall with a constant speed per particle.
@@ -52,20 +157,60 @@ fn handle_snow(
         let drift_offset = (time.elapsed_secs() + particle.drift_phase).sin();
         transform.translation.x += particle.drift_speed * drift_offset * delta;
 
-        if transform.translation.y < DESPAWN_Y {
+        if snow && transform.translation.y < DESPAWN_Y {
             commands.entity(entity).insert(Respawn);
         }
     }
 }
 
-// Respawn snow particles that have been marked as below the screen.
+// Ramp the snow boost toward full while the stereo is playing and back down when it stops.
+fn handle_snow_intensity(
+    time: Res<Time>,
+    boost: Res<SnowBoost>,
+    mut intensity: ResMut<SnowIntensity>,
+    query: Query<&State, With<Stereo>>,
+) {
+    let target = if query.iter().any(|state| *state == State::On) {
+        1.0
+    } else {
+        0.0
+    };
+
+    let step = time.delta_secs() / boost.ramp_seconds.max(f32::EPSILON);
+    intensity.0 = if intensity.0 < target {
+        (intensity.0 + step).min(target)
+    } else {
+        (intensity.0 - step).max(target)
+    };
+}
+
+// Respawn snow particles that have been marked as below the screen, boost particles only reappear while boosted.
 fn handle_snow_respawn(
     mut commands: Commands,
-    mut query: Query<(Entity, &mut Transform, &mut Sprite, &mut SnowParticle), (With<Snow>, With<Respawn>)>,
+    intensity: Res<SnowIntensity>,
+    mut query: Query<
+        (
+            Entity,
+            &mut Transform,
+            &mut Sprite,
+            &mut SnowParticle,
+            &mut Visibility,
+            Option<&BoostParticle>,
+        ),
+        (With<Snow>, With<Respawn>),
+    >,
 ) {
     let mut rng = rand::rng();
 
-    for (entity, mut transform, mut sprite, mut particle) in &mut query {
+    for (entity, mut transform, mut sprite, mut particle, mut visibility, boost_particle) in &mut query {
+        if let Some(boost_particle) = boost_particle {
+            *visibility = if intensity.0 > boost_particle.threshold {
+                Visibility::Inherited
+            } else {
+                Visibility::Hidden
+            };
+        }
+
         transform.translation.x = rng.random_range(SPAWN_X_MIN..=SPAWN_X_MAX);
         transform.translation.y = SPAWN_Y;
 
@@ -80,16 +225,21 @@ fn handle_snow_respawn(
     }
 }
 
-// Initialize snow particles distributed across the screen.
-fn init(mut commands: Commands) {
+// Initialize snow particles distributed across the screen, boost particles start hidden. With the intro enabled the
+// regular particles instead wait hidden at the top of the screen to be released.
+fn init(mut commands: Commands, boost: Res<SnowBoost>, first_snow: Res<FirstSnow>) {
     let mut rng = rand::rng();
 
-    for _ in 0..PARTICLE_COUNT {
+    for index in 0..PARTICLE_COUNT + boost.particles {
         let x = rng.random_range(SPAWN_X_MIN..=SPAWN_X_MAX);
-        let y = rng.random_range(DESPAWN_Y..=SPAWN_Y);
+        let y = if first_snow.enabled {
+            SPAWN_Y
+        } else {
+            rng.random_range(DESPAWN_Y..=SPAWN_Y)
+        };
         let opacity = rng.random_range(OPACITY_MIN..=OPACITY_MAX);
 
-        commands.spawn((
+        let mut particle = commands.spawn((
             Sprite {
                 color: Color::srgba(1.0, 1.0, 1.0, opacity),
                 custom_size: Some(Vec2::splat(1.0)),
@@ -103,5 +253,21 @@ fn init(mut commands: Commands) {
             },
             Snow,
         ));
+
+        if index >= PARTICLE_COUNT {
+            particle.insert((
+                BoostParticle {
+                    threshold: rng.random_range(0.0..1.0),
+                },
+                Visibility::Hidden,
+            ));
+        } else if first_snow.enabled {
+            particle.insert((
+                IntroParticle {
+                    threshold: rng.random_range(0.0..1.0),
+                },
+                Visibility::Hidden,
+            ));
+        }
     }
 }
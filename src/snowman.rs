@@ -1,22 +1,87 @@
 use bevy::prelude::*;
+use bevy_light_2d::prelude::*;
+use rand::Rng;
+
+use crate::{flickering_light::FlickeringLight, y_sort::YSorted};
 
 #[derive(Component)]
 struct Snowman;
 
+// Optional snowman decorations, a scarf tint drawn over the painted scarf and a faint glow from the carrot nose. Both
+// are off by default.
+#[derive(Clone, Default, Resource)]
+pub struct SnowmanDecorations {
+    pub scarf_color: Option<Color>,
+    pub nose_glow: Option<Color>,
+}
+
+// Carrot nose position relative to the snowman.
+const NOSE_OFFSET: Vec2 = Vec2::new(-0.5, 8.5);
+
 // Add the animation systems.
 pub fn add_systems(app: &mut App) {
-    app.add_systems(Startup, init);
+    app.init_resource::<SnowmanDecorations>().add_systems(Startup, init);
 }
 
 // Snowman initialization.
-fn init(mut commands: Commands, asset_server: Res<AssetServer>) {
+fn init(mut commands: Commands, asset_server: Res<AssetServer>, decorations: Res<SnowmanDecorations>) {
     let background = asset_server.load("snowman/snowman.png");
-    commands.spawn((
-        Sprite {
-            image: background,
-            ..default()
-        },
-        Transform::from_xyz(-124.0, -53.0, 1.0),
-        Snowman,
-    ));
+    let snowman = commands
+        .spawn((
+            Sprite {
+                image: background,
+                ..default()
+            },
+            // Layer 1 keeps the snowman behind the ground snow.
+            Transform::from_xyz(-124.0, -53.0, 0.0),
+            YSorted {
+                layer: 1.0,
+                foot_offset: -16.0,
+            },
+            Snowman,
+        ))
+        .id();
+
+    if let Some(color) = decorations.scarf_color {
+        let scarf = commands
+            .spawn((
+                Sprite {
+                    image: asset_server.load("snowman/scarf.png"),
+                    color,
+                    ..default()
+                },
+                Transform::from_xyz(0.0, 0.0, 0.01),
+            ))
+            .id();
+        commands.entity(snowman).add_child(scarf);
+    }
+
+    if let Some(color) = decorations.nose_glow {
+        let mut rng = rand::rng();
+        let nose = commands
+            .spawn((
+                Transform::from_translation(NOSE_OFFSET.extend(0.0)),
+                PointLight2d {
+                    color,
+                    intensity: 0.0,
+                    radius: 6.0,
+                    ..default()
+                },
+                FlickeringLight {
+                    seed: rng.random_range(0.0..1000.0),
+                    intensity_amplitude: 0.05,
+                    intensity_frequency: 0.5,
+                    intensity_min: 0.3,
+                    intensity_octaves: 1,
+                    color_frequency: 0.0,
+                    color_octaves: 1,
+                    color_seed_offset: 0.0,
+                    color_temperature: 1.0,
+                    colors: vec![color],
+                    time_offset: rng.random_range(0.0..100.0),
+                },
+            ))
+            .id();
+        commands.entity(snowman).add_child(nose);
+    }
 }
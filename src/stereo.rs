@@ -2,7 +2,8 @@ use bevy::{audio::Volume, prelude::*};
 
 use crate::{
     animation::AnimationConfig,
-    interaction::{Interactable, InteractionEvent, State},
+    audio::{AudioConfig, AudioUnlocked, Occluded, sound_change},
+    interaction::{InitialStates, Interactable, InteractionEvent, State},
 };
 
 #[derive(Clone, Resource)]
@@ -13,9 +14,16 @@ struct SpriteAssets {
 }
 
 #[derive(Component)]
-struct Stereo;
+pub struct Stereo;
 
-const INTERACTABLE_ID: &str = "stereo";
+// Index into the volume levels while the stereo is playing.
+#[derive(Component, Default)]
+struct VolumeLevel(usize);
+
+pub const INTERACTABLE_ID: &str = "stereo";
+
+// Volume steps relative to the configured stereo volume, interacting steps down through them before turning off.
+const VOLUME_LEVELS: [f32; 3] = [1.0, 0.6, 0.3];
 
 // Add the animation systems.
 pub fn add_systems(app: &mut App) {
@@ -48,19 +56,24 @@ fn handle_animations(time: Res<Time>, mut query: Query<(&mut AnimationConfig, &m
     }
 }
 
-// Listen for interaction events and update the state.
+// Listen for interaction events and update the state, stepping the volume down before turning off.
+// NOTE: the level goes on the sink, still scaled by any occlusion, and into the playback settings for a sink that
+// hasn't been created yet.
 fn handle_interaction(
     sprite_assets: Res<SpriteAssets>,
+    audio_config: Res<AudioConfig>,
     mut events: MessageReader<InteractionEvent>,
-    mut query: Query<(&mut State, &mut Sprite), With<Stereo>>,
+    mut query: Query<(&mut State, &mut Sprite, &mut VolumeLevel, &mut PlaybackSettings), With<Stereo>>,
+    mut sinks: Query<(&mut SpatialAudioSink, Option<&Occluded>), With<Stereo>>,
 ) {
     for event in events.read() {
         if event.id == INTERACTABLE_ID
-            && let Ok((mut state, mut sprite)) = query.single_mut()
+            && let Ok((mut state, mut sprite, mut level, mut settings)) = query.single_mut()
         {
             match *state {
                 State::Off => {
                     *state = State::On;
+                    level.0 = 0;
                     sprite.image = sprite_assets.running_sprite.clone();
                     sprite.texture_atlas = Some(TextureAtlas {
                         layout: sprite_assets.running_layout.clone(),
@@ -68,29 +81,34 @@ fn handle_interaction(
                     });
                 }
 
+                State::On if level.0 + 1 < VOLUME_LEVELS.len() => {
+                    level.0 += 1;
+                }
+
                 State::On => {
                     *state = State::Off;
                     sprite.image = sprite_assets.off_sprite.clone();
                     sprite.texture_atlas = None;
                 }
             }
+
+            let volume = audio_config.stereo * VOLUME_LEVELS[level.0];
+            settings.volume = Volume::Linear(volume);
+            if let Ok((mut sink, occluded)) = sinks.single_mut() {
+                sink.set_volume(Volume::Linear(volume * occluded.map_or(1.0, |occluded| occluded.0)));
+            }
         }
     }
 }
 
-// Control audio playback based on stereo state
-fn handle_sound(query: Query<(&State, &mut SpatialAudioSink), (With<Stereo>, Changed<State>)>) {
+// Control audio playback based on stereo state.
+// NOTE: reconciled every frame so quick toggles, or a sink created after the toggle, can't leave it out of sync.
+fn handle_sound(unlocked: Res<AudioUnlocked>, query: Query<(&State, &SpatialAudioSink), With<Stereo>>) {
     for (state, audio_sink) in &query {
-        match *state {
-            // Start the stereo sound effect if it isn't already running.
-            State::On => {
-                audio_sink.play();
-            }
-
-            // Remove any existing sound effects.
-            State::Off => {
-                audio_sink.pause();
-            }
+        match sound_change(*state, audio_sink.is_paused(), unlocked.0) {
+            Some(true) => audio_sink.play(),
+            Some(false) => audio_sink.pause(),
+            None => {}
         }
     }
 }
@@ -99,6 +117,8 @@ fn handle_sound(query: Query<(&State, &mut SpatialAudioSink), (With<Stereo>, Cha
 fn init(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
+    audio_config: Res<AudioConfig>,
+    initial_states: Res<InitialStates>,
     mut texture_layouts: ResMut<Assets<TextureAtlasLayout>>,
 ) {
     // Load the running sprite sheet.
@@ -109,21 +129,33 @@ fn init(
     };
     commands.insert_resource(sprite.clone());
 
-    // Create the sprite starting in the off state.
+    // Create the sprite in its configured starting state, the music follows the state once spawned.
+    let state = initial_states.get(INTERACTABLE_ID);
+    let (image, texture_atlas) = match state {
+        State::On => (
+            sprite.running_sprite,
+            Some(TextureAtlas {
+                layout: sprite.running_layout,
+                index: 0,
+            }),
+        ),
+        State::Off => (sprite.off_sprite, None),
+    };
     commands.spawn((
         Sprite {
-            image: sprite.off_sprite,
-            texture_atlas: None,
+            image,
+            texture_atlas,
             ..default()
         },
         Transform::from_translation(Vec3::new(18.0, -46.0, 5.0)),
         Stereo,
+        VolumeLevel::default(),
         AnimationConfig::new(0, 4, 4),
-        State::Off,
+        state,
         AudioPlayer::new(asset_server.load("stereo/merry_little_christmas.ogg")),
         PlaybackSettings::LOOP
             .with_spatial(true)
-            .with_volume(Volume::Linear(0.9))
+            .with_volume(Volume::Linear(audio_config.stereo))
             .paused(),
         Interactable {
             id: INTERACTABLE_ID.to_string(),
@@ -135,3 +167,46 @@ fn init(
         },
     ));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interacting_steps_the_volume_down_before_turning_off() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_message::<InteractionEvent>()
+            .init_resource::<AudioConfig>()
+            .insert_resource(SpriteAssets {
+                running_sprite: default(),
+                running_layout: default(),
+                off_sprite: default(),
+            })
+            .add_systems(Update, handle_interaction);
+        let stereo = app
+            .world_mut()
+            .spawn((
+                Stereo,
+                State::Off,
+                Sprite::default(),
+                VolumeLevel::default(),
+                PlaybackSettings::LOOP,
+            ))
+            .id();
+        let full = app.world().resource::<AudioConfig>().stereo;
+
+        // The last step turns it off, the settings keep the quietest volume until it's turned back on at full volume.
+        for (state, level) in [(State::On, 1.0), (State::On, 0.6), (State::On, 0.3), (State::Off, 0.3)] {
+            app.world_mut().write_message(InteractionEvent {
+                id: INTERACTABLE_ID.to_string(),
+            });
+            app.update();
+
+            let world = app.world();
+            assert!(*world.get::<State>(stereo).unwrap() == state);
+            let volume = world.get::<PlaybackSettings>(stereo).unwrap().volume.to_linear();
+            assert!((volume - full * level).abs() < 1e-4);
+        }
+    }
+}
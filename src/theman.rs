@@ -1,21 +1,25 @@
-use bevy::{audio::Volume, prelude::*};
+use bevy::{ecs::system::SystemParam, prelude::*};
 use rand::{Rng, rng};
 use std::time::Duration;
 
 use crate::{
     animation::AnimationConfig,
-    chair,
-    input::{Direction, InputEvent},
-    interaction::{InRange, InteractionEvent, Interactor},
+    audio::{AudioConfig, play_one_shot},
+    house::{Region, SceneRegions},
+    input::{Direction, InputEvent, Intent},
+    interaction::{self, InRange, Interactable, InteractionEvent, Interactor},
+    sampling::{SmoothSampling, SubPixelRendering},
     santa::SantasHereEvent,
+    y_sort::YSorted,
 };
 
 #[derive(Component, Clone, Copy, Debug, PartialEq)]
-enum State {
+pub enum State {
     Idle,
     Action,
     Walking,
     Sitting,
+    StandingUp,
 }
 
 #[derive(Component, Clone, Copy, PartialEq)]
@@ -24,18 +28,47 @@ enum FootStep {
     Right,
 }
 
+#[derive(Component)]
+struct ActionTimer(Timer);
+
+// A puff of breath drifting the way the man was facing, fading out over its lifetime.
+#[derive(Component)]
+struct BreathPuff {
+    age: f32,
+    drift: f32,
+}
+
+#[derive(Component)]
+struct BreathTimer(Timer);
+
 #[derive(Component)]
 struct IdleTimer(Timer);
 
+// Counts down the time sitting before Santa is summoned.
+#[derive(Component)]
+struct SantaDelay(Timer);
+
 #[derive(Component)]
 struct StepTimer(Timer);
 
+// Interact press waiting for something to come into range.
+#[derive(Default, Resource)]
+struct InteractionBuffer(Option<Timer>);
+
 #[derive(Component)]
 struct Navigation {
     x: f32,
     action: bool,
 }
 
+// Brief squash and stretch when setting off or stopping, stretch is positive when setting off.
+#[derive(Component, Default)]
+struct Squash {
+    timer: Option<Timer>,
+    stretch: f32,
+    walking: bool,
+}
+
 #[derive(Clone, Resource)]
 struct AudioAssets {
     left_steps: Vec<Handle<AudioSource>>,
@@ -50,35 +83,264 @@ struct SpriteAssets {
     sitting_layout: Handle<TextureAtlasLayout>,
     standing_sprite: Handle<Image>,
     standing_layout: Handle<TextureAtlasLayout>,
+    seated_idle: SeatIdle,
 }
 
 #[derive(Component)]
 pub struct TheMan;
 
+// Add to seats alongside their Interactable to declare the pose the man sits in, the offset places him relative to the
+// seat and the animation plays once on sitting down and in reverse on standing up. The idle animation loops once he's
+// sat down, without one he holds the last sitting frame. Lean tilts him back by that many radians while he's sat, he
+// sits up again as he starts standing.
+#[derive(Clone, Component)]
+pub struct SeatPose {
+    pub sprite: Handle<Image>,
+    pub layout: Handle<TextureAtlasLayout>,
+    pub fps: u8,
+    pub offset: Vec2,
+    pub lean: f32,
+    pub idle: Option<SeatIdle>,
+}
+
+// Looping animation played while sat down, starting from the last sitting frame.
+#[derive(Clone)]
+pub struct SeatIdle {
+    pub sprite: Handle<Image>,
+    pub layout: Handle<TextureAtlasLayout>,
+    pub fps: u8,
+}
+
+// The pose of the seat the man last sat on, sitting without one uses his own sitting animation.
+#[derive(Component)]
+struct Seated(SeatPose);
+
+// Santa visits summoned by sitting down so far.
+#[derive(Default, Resource)]
+struct SantaVisits(u32);
+
+// Added while the seated idle animation is looping.
+#[derive(Component)]
+struct SeatIdling;
+
+// Man behaviour settings.
+#[derive(Clone, Resource)]
+pub struct TheManConfig {
+    // Seconds spent in the action state before returning to idle.
+    pub action_timeout: f32,
+    // Seconds spent sitting peacefully before Santa arrives.
+    pub santa_delay: f32,
+    // Distance covered per step, footsteps are timed from this and the walking speed.
+    pub stride_length: f32,
+    // Loop the seat's idle animation once sat down, off holds the last sitting frame.
+    pub seated_idle: bool,
+    // Visits sitting down can summon Santa for in a session, None for no limit.
+    pub santa_visit_limit: Option<u32>,
+    // Distance between the listener's ears, positive with the left ear toward -x. Larger gaps widen the stereo image.
+    pub listener_ear_offset: f32,
+}
+
+impl TheManConfig {
+    // Seconds between footsteps at the walking speed.
+    pub fn step_interval(&self) -> f32 {
+        self.stride_length / WALKING_SPEED
+    }
+}
+
+impl Default for TheManConfig {
+    fn default() -> Self {
+        Self {
+            action_timeout: 1.5,
+            santa_delay: 2.0,
+            stride_length: DEFAULT_STRIDE_LENGTH,
+            seated_idle: true,
+            santa_visit_limit: None,
+            listener_ear_offset: 10.0,
+        }
+    }
+}
+
+// Cold breath settings, puffs appear by the man's head every interval seconds while he's standing or walking outside.
+#[derive(Clone, Resource)]
+pub struct ColdBreath {
+    pub interval: f32,
+    pub size: f32,
+    pub lifetime: f32,
+}
+
+impl Default for ColdBreath {
+    fn default() -> Self {
+        Self {
+            interval: 2.0,
+            size: 2.0,
+            lifetime: 1.0,
+        }
+    }
+}
+
+// Blips played on an interact press, confirm when something's in range and error when the press finds nothing. The
+// sounds are asset paths and either can be left unset, disabled silences both.
+#[derive(Clone, Default, Resource)]
+pub struct InteractFeedback {
+    pub enabled: bool,
+    pub confirm: Option<String>,
+    pub error: Option<String>,
+    pub volume: f32,
+}
+
+// Everything needed to play the interact feedback sounds.
+#[derive(SystemParam)]
+struct FeedbackPlayer<'w, 's> {
+    commands: Commands<'w, 's>,
+    asset_server: Res<'w, AssetServer>,
+    audio_config: Res<'w, AudioConfig>,
+    feedback: Res<'w, InteractFeedback>,
+}
+
+impl FeedbackPlayer<'_, '_> {
+    fn play_confirm(&mut self) {
+        self.play(self.feedback.confirm.clone());
+    }
+
+    fn play_error(&mut self) {
+        self.play(self.feedback.error.clone());
+    }
+
+    // Play a feedback sound if feedback is enabled and the sound is set.
+    fn play(&mut self, sound: Option<String>) {
+        if let Some(sound) = sound
+            && self.feedback.enabled
+        {
+            let source = self.asset_server.load(sound);
+            play_one_shot(&mut self.commands, &self.audio_config, source, self.feedback.volume);
+        }
+    }
+}
+
+// Message to place the man instantly for scripted scenes, the position is clamped to where he can walk.
+#[derive(Message)]
+pub struct TeleportEvent {
+    pub position: Vec2,
+    pub state: State,
+    pub direction: Direction,
+}
+
+// Add to entities the man can't walk through, he stops at the edge when approaching from either side.
+#[derive(Component)]
+pub struct SolidObstacle {
+    pub half_width: f32,
+}
+
+// Breath puff offset from the man's position when facing right, drift speed, and starting opacity.
+const BREATH_OFFSET: Vec2 = Vec2::new(5.0, 9.0);
+const BREATH_DRIFT: f32 = 6.0;
+const BREATH_OPACITY: f32 = 0.5;
+
+// Distance within which a lit prop draws the man's gaze while idle.
+const LOOK_DISTANCE: f32 = 60.0;
+
+// Seconds an interact press stays buffered while nothing is in range.
+const INTERACTION_BUFFER: f32 = 0.15;
+
+// Standing interactor box, the height grows while reaching up or crouching down for a prop outside of it.
+const INTERACTOR_WIDTH: f32 = 13.0;
+const INTERACTOR_HEIGHT: f32 = 32.0;
+const REACH_HEIGHT: f32 = 48.0;
+
+// Interactor box offset from the man's center, lowered toward his feet so low props are in reach.
+const INTERACTOR_OFFSET: Vec2 = Vec2::new(0.0, -4.0);
+
+const SEATED_IDLE_FPS: u8 = 2;
+const SITTING_FPS: u8 = 10;
+const WALKING_FPS: u8 = 10;
+
+// Peak scale change and duration of the squash and stretch.
+const SQUASH_AMOUNT: f32 = 0.04;
+const SQUASH_SECONDS: f32 = 0.2;
+
+// Walkable range, the left end is past the door on the snow outside the house.
+const WALKING_MIN_X: f32 = -134.0;
+const WALKING_MAX_X: f32 = 160.0;
 const WALKING_SPEED: f32 = 30.0;
-const WALKING_VOLUME: f32 = 0.85;
-const WALKING_TIMER: f32 = 0.45;
+
+// The default stride keeps the original 0.45 second step interval.
+const DEFAULT_STRIDE_LENGTH: f32 = 13.5;
+const _: () = assert!((DEFAULT_STRIDE_LENGTH / WALKING_SPEED - 0.45).abs() < 1e-6);
 
 // Add the animation systems.
 pub fn add_systems(app: &mut App) {
-    app.add_message::<InputEvent>().add_systems(Startup, init).add_systems(
-        Update,
-        (
-            handle_audio,
-            handle_animations,
-            handle_animation_state_change.before(handle_animations),
-            handle_interactions,
-            handle_messages.before(handle_animation_state_change),
-            handle_movement,
-            handle_idle_action,
-            handle_chair_interaction,
-        ),
-    );
+    app.add_message::<InputEvent>()
+        .add_message::<TeleportEvent>()
+        .init_resource::<ColdBreath>()
+        .init_resource::<InteractFeedback>()
+        .init_resource::<InteractionBuffer>()
+        .init_resource::<SantaVisits>()
+        .init_resource::<TheManConfig>()
+        .add_systems(Startup, init)
+        .add_systems(
+            Update,
+            (
+                handle_action_timeout,
+                handle_audio,
+                handle_animations,
+                handle_animation_state_change.before(handle_animations),
+                handle_breath,
+                handle_breath_puffs,
+                handle_interactions,
+                handle_messages.before(handle_animation_state_change),
+                handle_movement,
+                handle_reach,
+                handle_idle_action,
+                handle_santa_delay,
+                handle_seat_interaction.before(handle_animation_state_change),
+                handle_squash.after(handle_messages),
+                handle_teleport.before(handle_animation_state_change),
+            ),
+        );
+}
+
+// Return to idle after lingering in the action state, the timer restarts on every entry and picks up timeout changes.
+fn handle_action_timeout(
+    time: Res<Time>,
+    config: Res<TheManConfig>,
+    mut query: Query<(&mut State, &mut ActionTimer), With<TheMan>>,
+) {
+    for (mut state, mut timer) in &mut query {
+        if *state != State::Action {
+            timer
+                .0
+                .set_duration(Duration::from_secs_f32(config.action_timeout.max(0.0)));
+            timer.0.reset();
+            continue;
+        }
+
+        timer.0.tick(time.delta());
+        if timer.0.just_finished() {
+            *state = State::Idle;
+        }
+    }
 }
 
 // Advance animation frames and states.
-fn handle_animations(time: Res<Time>, mut query: Query<(&State, &mut AnimationConfig, &mut Sprite), With<TheMan>>) {
-    for (state, mut config, mut sprite) in &mut query {
+fn handle_animations(
+    mut commands: Commands,
+    time: Res<Time>,
+    man_config: Res<TheManConfig>,
+    sprite_assets: Res<SpriteAssets>,
+    layouts: Res<Assets<TextureAtlasLayout>>,
+    mut query: Query<
+        (
+            Entity,
+            &mut State,
+            &mut AnimationConfig,
+            &mut Sprite,
+            Option<&Seated>,
+            Has<SeatIdling>,
+        ),
+        With<TheMan>,
+    >,
+) {
+    for (entity, mut state, mut config, mut sprite, seated, idling) in &mut query {
         // Idle and Action states don't have animations.
         if matches!(*state, State::Idle | State::Action) {
             continue;
@@ -86,40 +348,93 @@ fn handle_animations(time: Res<Time>, mut query: Query<(&State, &mut AnimationCo
 
         config.frame_timer.tick(time.delta());
 
+        let mut start_idle = None;
         if config.frame_timer.just_finished()
             && let Some(atlas) = &mut sprite.texture_atlas
         {
             match *state {
-                // Sitting animation plays once and remains on last frame.
+                // Sat down the idle animation loops.
+                State::Sitting if idling => {
+                    atlas.index = config.next_loop(atlas.index);
+                    config.frame_timer = AnimationConfig::timer_from_fps(config.fps);
+                }
+
+                // Sitting animation plays once, then moves on to the seated idle or remains on the last frame.
                 State::Sitting => {
-                    if atlas.index < config.last_index {
-                        atlas.index += 1;
+                    if let Some(index) = config.next_once(atlas.index) {
+                        atlas.index = index;
                         config.frame_timer = AnimationConfig::timer_from_fps(config.fps);
+                    } else if man_config.seated_idle {
+                        start_idle = match seated {
+                            Some(Seated(pose)) => pose.idle.clone(),
+                            None => Some(sprite_assets.seated_idle.clone()),
+                        };
                     }
                 }
 
+                // Standing up plays the sitting animation in reverse, then he's idle.
+                State::StandingUp => match config.next_once(atlas.index) {
+                    Some(index) => {
+                        atlas.index = index;
+                        config.frame_timer = AnimationConfig::timer_from_fps(config.fps);
+                    }
+                    None => *state = State::Idle,
+                },
+
                 // Walking animation loops continuously.
                 State::Walking => {
-                    if atlas.index == config.last_index {
-                        atlas.index = config.first_index;
-                    } else {
-                        atlas.index += 1;
-                    }
+                    atlas.index = config.next_loop(atlas.index);
                     config.frame_timer = AnimationConfig::timer_from_fps(config.fps);
                 }
 
                 State::Idle | State::Action => {}
             }
         }
+
+        if let Some(idle) = start_idle {
+            sprite.image = idle.sprite;
+            sprite.texture_atlas = Some(TextureAtlas {
+                layout: idle.layout.clone(),
+                index: 0,
+            });
+            set_animation(&mut config, &layouts, &idle.layout, idle.fps);
+            commands.entity(entity).insert(SeatIdling);
+        }
     }
 }
 
-// Handle sprite swapping on state changes.
+// Handle sprite swapping on state changes, any change ends the seated idle.
 fn handle_animation_state_change(
+    mut commands: Commands,
     sprite_assets: Res<SpriteAssets>,
-    mut query: Query<(&State, &mut Sprite, &mut AnimationConfig, &Direction), (With<TheMan>, Changed<State>)>,
+    layouts: Res<Assets<TextureAtlasLayout>>,
+    mut query: Query<
+        (
+            Entity,
+            &State,
+            &mut Sprite,
+            &mut AnimationConfig,
+            &Direction,
+            Option<&Seated>,
+            Has<SeatIdling>,
+        ),
+        (With<TheMan>, Changed<State>),
+    >,
 ) {
-    for (state, mut sprite, mut config, direction) in &mut query {
+    for (entity, state, mut sprite, mut config, direction, seated, idling) in &mut query {
+        if idling {
+            commands.entity(entity).remove::<SeatIdling>();
+        }
+
+        let (sitting_sprite, sitting_layout, sitting_fps) = match seated {
+            Some(Seated(pose)) => (&pose.sprite, &pose.layout, pose.fps),
+            None => (
+                &sprite_assets.sitting_sprite,
+                &sprite_assets.sitting_layout,
+                SITTING_FPS,
+            ),
+        };
+
         match *state {
             State::Idle => {
                 sprite.image = sprite_assets.standing_sprite.clone();
@@ -137,23 +452,31 @@ fn handle_animation_state_change(
                     index: 0,
                 });
                 sprite.flip_x = *direction == Direction::Left;
-                config.first_index = 0;
-                config.last_index = 8;
-                config.fps = 10;
-                config.frame_timer = AnimationConfig::timer_from_fps(10);
+                set_animation(&mut config, &layouts, &sprite_assets.walking_layout, WALKING_FPS);
             }
 
             State::Sitting => {
-                sprite.image = sprite_assets.sitting_sprite.clone();
+                sprite.image = sitting_sprite.clone();
                 sprite.texture_atlas = Some(TextureAtlas {
-                    layout: sprite_assets.sitting_layout.clone(),
+                    layout: sitting_layout.clone(),
                     index: 0,
                 });
                 sprite.flip_x = false;
-                config.first_index = 0;
-                config.last_index = 4;
-                config.fps = 10;
-                config.frame_timer = AnimationConfig::timer_from_fps(10);
+                set_animation(&mut config, &layouts, sitting_layout, sitting_fps);
+            }
+
+            // Keep the sitting sprite and its current frame, playing back toward the first. Coming out of the seated
+            // idle the sitting sprite is put back on its last frame.
+            State::StandingUp => {
+                set_animation(&mut config, &layouts, sitting_layout, sitting_fps);
+                config.reverse = true;
+                if idling {
+                    sprite.image = sitting_sprite.clone();
+                    sprite.texture_atlas = Some(TextureAtlas {
+                        layout: sitting_layout.clone(),
+                        index: config.last_index,
+                    });
+                }
             }
 
             State::Action => {
@@ -169,8 +492,11 @@ fn handle_audio(
     mut commands: Commands,
     time: Res<Time>,
     audio_assets: Res<AudioAssets>,
+    audio_config: Res<AudioConfig>,
+    config: Res<TheManConfig>,
     mut query: Query<(&State, &mut StepTimer, &mut FootStep), With<TheMan>>,
 ) {
+    let step_interval = config.step_interval();
     for (state, mut timer, mut footstep) in &mut query {
         match *state {
             State::Walking => {
@@ -178,121 +504,177 @@ fn handle_audio(
                 if timer.0.just_finished() {
                     match *footstep {
                         FootStep::Left => {
-                            commands.spawn((
-                                AudioPlayer::new(
-                                    audio_assets.left_steps[rng().random_range(0..audio_assets.left_steps.len())]
-                                        .clone(),
-                                ),
-                                PlaybackSettings::DESPAWN.with_volume(Volume::Linear(WALKING_VOLUME)),
-                            ));
-                            timer.0.set_duration(Duration::from_secs_f32(WALKING_TIMER));
+                            play_one_shot(
+                                &mut commands,
+                                &audio_config,
+                                audio_assets.left_steps[rng().random_range(0..audio_assets.left_steps.len())].clone(),
+                                audio_config.footsteps,
+                            );
+                            timer.0.set_duration(Duration::from_secs_f32(step_interval));
                             *footstep = FootStep::Right;
                         }
 
                         FootStep::Right => {
-                            commands.spawn((
-                                AudioPlayer::new(
-                                    audio_assets.right_steps[rng().random_range(0..audio_assets.right_steps.len())]
-                                        .clone(),
-                                ),
-                                PlaybackSettings::DESPAWN.with_volume(Volume::Linear(WALKING_VOLUME)),
-                            ));
-                            timer.0.set_duration(Duration::from_secs_f32(WALKING_TIMER));
+                            play_one_shot(
+                                &mut commands,
+                                &audio_config,
+                                audio_assets.right_steps[rng().random_range(0..audio_assets.right_steps.len())].clone(),
+                                audio_config.footsteps,
+                            );
+                            timer.0.set_duration(Duration::from_secs_f32(step_interval));
                             *footstep = FootStep::Left;
                         }
                     }
                 }
             }
+            // The first step lands half an interval after setting off.
             _ => {
-                timer.0.set_duration(Duration::from_secs_f32(0.225));
+                timer.0.set_duration(Duration::from_secs_f32(step_interval / 2.0));
             }
         }
     }
 }
 
-// Handle chair-specific interactions for sitting/standing.
-fn handle_chair_interaction(
-    sprite_assets: Res<SpriteAssets>,
-    mut events: MessageReader<InteractionEvent>,
-    mut santa_events: MessageWriter<SantasHereEvent>,
-    mut man_query: Query<(&mut State, &mut Sprite, &mut Transform, &mut AnimationConfig), With<TheMan>>,
+// Change the man's direction using the idle timer, he looks toward the nearest lit prop if one is close.
+fn handle_idle_action(
+    time: Res<Time>,
+    props: Query<(&GlobalTransform, &interaction::State), With<Interactable>>,
+    mut query: Query<(&mut IdleTimer, &mut Sprite, &State, &Transform), With<TheMan>>,
 ) {
-    for event in events.read() {
-        if event.id != chair::INTERACTABLE_ID {
-            continue;
-        }
-
-        if let Ok((mut state, mut sprite, mut transform, mut config)) = man_query.single_mut() {
-            match *state {
-                State::Action => {
-                    // Teleport to the chair sitting position.
-                    transform.translation.x = 74.0;
-                    transform.translation.y = -56.0;
-                    transform.translation.z = 4.0;
-
-                    // Switch to the sitting sprite textures and configuration.
-                    sprite.image = sprite_assets.sitting_sprite.clone();
-                    sprite.texture_atlas = Some(TextureAtlas {
-                        layout: sprite_assets.sitting_layout.clone(),
-                        index: 0,
-                    });
-                    sprite.flip_x = false;
-
-                    config.first_index = 0;
-                    config.last_index = 4;
-                    config.fps = 10;
-                    config.frame_timer = AnimationConfig::timer_from_fps(10);
-
-                    *state = State::Sitting;
-                    santa_events.write(SantasHereEvent);
-                }
-
-                State::Sitting => {
-                    transform.translation.z = 10.0;
+    for (mut timer, mut sprite, state, transform) in &mut query {
+        if *state == State::Idle {
+            timer.0.tick(time.delta());
+            if timer.0.just_finished() {
+                let man_x = transform.translation.x;
+                let lit_prop_x = props
+                    .iter()
+                    .filter(|(_, prop_state)| **prop_state == interaction::State::On)
+                    .map(|(prop_transform, _)| prop_transform.translation().x)
+                    .filter(|prop_x| (prop_x - man_x).abs() <= LOOK_DISTANCE)
+                    .min_by(|x_1, x_2| (x_1 - man_x).abs().total_cmp(&(x_2 - man_x).abs()));
 
-                    // Switch to standing sprite
-                    sprite.image = sprite_assets.standing_sprite.clone();
-                    sprite.texture_atlas = Some(TextureAtlas {
-                        layout: sprite_assets.standing_layout.clone(),
-                        index: 0,
-                    });
+                sprite.flip_x = match lit_prop_x {
+                    Some(prop_x) => prop_x < man_x,
+                    None => !sprite.flip_x,
+                };
+            }
+        } else {
+            timer.0.reset();
+        }
+    }
+}
 
-                    *state = State::Idle;
-                }
+// Breathe out a puff on a timer while idle or walking outside the house.
+fn handle_breath(
+    mut commands: Commands,
+    time: Res<Time>,
+    breath: Res<ColdBreath>,
+    regions: Res<SceneRegions>,
+    mut query: Query<(&State, &Direction, &Transform, &mut BreathTimer), With<TheMan>>,
+) {
+    for (state, direction, transform, mut timer) in &mut query {
+        if regions.region_at(transform.translation.x) != Region::Outdoor
+            || !matches!(*state, State::Idle | State::Walking)
+        {
+            continue;
+        }
 
-                _ => {}
-            }
+        timer.0.tick(time.delta());
+        if !timer.0.just_finished() {
+            continue;
         }
+
+        let facing = match direction {
+            Direction::Left => -1.0,
+            Direction::Right => 1.0,
+        };
+        let position = transform.translation.truncate() + BREATH_OFFSET * Vec2::new(facing, 1.0);
+        commands.spawn((
+            Sprite {
+                color: Color::srgba(0.9, 0.9, 0.95, BREATH_OPACITY),
+                custom_size: Some(Vec2::splat(breath.size)),
+                ..default()
+            },
+            Transform::from_translation(position.extend(transform.translation.z + 0.1)),
+            BreathPuff {
+                age: 0.0,
+                drift: BREATH_DRIFT * facing,
+            },
+        ));
     }
 }
 
-// Change the man's direction using the idle timer.
-fn handle_idle_action(time: Res<Time>, mut query: Query<(&mut IdleTimer, &mut Sprite, &State), With<TheMan>>) {
-    for (mut timer, mut sprite, state) in &mut query {
-        if *state == State::Idle {
-            timer.0.tick(time.delta());
-            if timer.0.just_finished() {
-                sprite.flip_x = !sprite.flip_x;
-            }
-        } else {
-            timer.0.reset();
+// Drift and fade the breath puffs, despawning them at the end of their lifetime.
+fn handle_breath_puffs(
+    mut commands: Commands,
+    time: Res<Time>,
+    breath: Res<ColdBreath>,
+    mut query: Query<(Entity, &mut BreathPuff, &mut Transform, &mut Sprite)>,
+) {
+    let delta = time.delta_secs();
+
+    for (entity, mut puff, mut transform, mut sprite) in &mut query {
+        puff.age += delta;
+        if puff.age >= breath.lifetime {
+            commands.entity(entity).despawn();
+            continue;
         }
+
+        transform.translation.x += puff.drift * delta;
+        transform.scale = Vec3::splat(1.0 + puff.age / breath.lifetime);
+        sprite
+            .color
+            .set_alpha(BREATH_OPACITY * (1.0 - puff.age / breath.lifetime));
     }
 }
 
 // Interact with objects when they're in range and the man is in the action state.
+// NOTE: entering the action state with nothing in range buffers the press, so walking into range shortly after
+// still interacts.
 fn handle_interactions(
+    time: Res<Time>,
+    mut feedback: FeedbackPlayer,
+    mut buffer: ResMut<InteractionBuffer>,
     mut interaction_events: MessageWriter<InteractionEvent>,
-    state_query: Query<&State, (With<TheMan>, Changed<State>)>,
+    state_query: Query<Ref<State>, With<TheMan>>,
     range_query: Query<&InRange>,
+    added_range_query: Query<&InRange, Added<InRange>>,
 ) {
     for state in &state_query {
-        for in_range in &range_query {
-            if *state == State::Action {
+        if *state != State::Action {
+            buffer.0 = None;
+            continue;
+        }
+
+        if state.is_changed() {
+            if range_query.is_empty() {
+                buffer.0 = Some(Timer::from_seconds(INTERACTION_BUFFER, TimerMode::Once));
+            } else {
+                feedback.play_confirm();
+            }
+
+            for in_range in &range_query {
                 interaction_events.write(InteractionEvent {
                     id: in_range.id.clone(),
                 });
             }
+            continue;
+        }
+
+        let Some(timer) = &mut buffer.0 else {
+            continue;
+        };
+        timer.tick(time.delta());
+
+        if let Some(in_range) = added_range_query.iter().next() {
+            interaction_events.write(InteractionEvent {
+                id: in_range.id.clone(),
+            });
+            buffer.0 = None;
+            feedback.play_confirm();
+        } else if timer.just_finished() {
+            buffer.0 = None;
+            feedback.play_error();
         }
     }
 }
@@ -306,53 +688,44 @@ fn handle_messages(
     let (entity, mut state, mut direction, transform) = query.into_inner();
 
     for event in events.read() {
-        match (event.direction, event.target) {
-            (None, None) => {
-                if *state != State::Action && *state != State::Sitting {
+        match event.intent {
+            Intent::Idle => {
+                if !matches!(*state, State::Action | State::Sitting | State::StandingUp) {
                     *state = State::Idle;
                 }
             }
 
-            (Some(event_direction), None) => match event_direction {
-                Direction::Left | Direction::Right => {
-                    *state = State::Walking;
-                    *direction = event_direction;
-                }
+            Intent::Move(event_direction) => {
+                *state = State::Walking;
+                *direction = event_direction;
+            }
 
-                Direction::Up => {
-                    *state = State::Action;
-                }
-            },
+            // Interacting while seated stands back up.
+            Intent::Interact => {
+                *state = match *state {
+                    State::Sitting | State::StandingUp => State::StandingUp,
+                    _ => State::Action,
+                };
+            }
 
-            (None, Some(target)) => {
+            Intent::Navigate(target) => {
                 let event_direction = if target.x > transform.translation.x {
                     Direction::Right
                 } else if target.x < transform.translation.x {
                     Direction::Left
                 } else {
-                    Direction::Up
+                    // Already at the target.
+                    commands.entity(entity).remove::<Navigation>();
+                    *state = State::Idle;
+                    continue;
                 };
 
                 commands.entity(entity).insert(Navigation {
                     x: target.x,
                     action: target.action,
                 });
-
-                match event_direction {
-                    Direction::Left | Direction::Right => {
-                        *state = State::Walking;
-                        *direction = event_direction;
-                    }
-
-                    Direction::Up => {
-                        *state = State::Idle;
-                        *direction = Direction::Up;
-                    }
-                }
-            }
-
-            (Some(_), Some(_)) => {
-                println!("received input event with both direction and target data, ignoring!");
+                *state = State::Walking;
+                *direction = event_direction;
             }
         }
     }
@@ -362,15 +735,27 @@ fn handle_messages(
 fn handle_movement(
     time: Res<Time>,
     mut commands: Commands,
-    query: Query<(Entity, &mut State, &Direction, &mut Transform, Option<&Navigation>), With<TheMan>>,
+    query: Query<
+        (
+            Entity,
+            &mut State,
+            &Direction,
+            &mut Transform,
+            &Interactor,
+            Option<&Navigation>,
+        ),
+        With<TheMan>,
+    >,
+    obstacles: Query<(&GlobalTransform, &SolidObstacle)>,
 ) {
-    for (entity, mut state, direction, mut transform, navigation) in query {
+    for (entity, mut state, direction, mut transform, interactor, navigation) in query {
         match *state {
             State::Walking => {
-                // Check navigation status.
+                // Check navigation status, targets past the walking bounds are reached at the bound.
                 if let Some(target) = navigation
-                    && ((*direction == Direction::Left && transform.translation.x <= target.x)
-                        || (*direction == Direction::Right && transform.translation.x >= target.x))
+                    && let target_x = target.x.clamp(WALKING_MIN_X, WALKING_MAX_X)
+                    && ((*direction == Direction::Left && transform.translation.x <= target_x)
+                        || (*direction == Direction::Right && transform.translation.x >= target_x))
                 {
                     *state = if target.action { State::Action } else { State::Idle };
                     commands.entity(entity).remove::<Navigation>();
@@ -378,32 +763,195 @@ fn handle_movement(
                 }
 
                 // Walking transformation.
+                let previous_x = transform.translation.x;
                 match *direction {
                     Direction::Left => {
                         transform.translation.x -= WALKING_SPEED * time.delta_secs();
-                        transform.translation.x = transform.translation.x.max(-82.0);
-                        transform.translation.z = 10.0;
+                        transform.translation.x = transform.translation.x.max(WALKING_MIN_X);
                     }
 
                     Direction::Right => {
                         transform.translation.x += WALKING_SPEED * time.delta_secs();
-                        transform.translation.x = transform.translation.x.min(160.0);
-                        transform.translation.z = 10.0;
+                        transform.translation.x = transform.translation.x.min(WALKING_MAX_X);
+                    }
+                }
+
+                // Stop at the edge of any obstacle walked into, ending navigation since the target can't be reached.
+                let half_width = interactor.width / 2.0;
+                for (obstacle_transform, obstacle) in &obstacles {
+                    let left_edge = obstacle_transform.translation().x - obstacle.half_width - half_width;
+                    let right_edge = obstacle_transform.translation().x + obstacle.half_width + half_width;
+
+                    let edge = if previous_x <= left_edge && transform.translation.x > left_edge {
+                        left_edge
+                    } else if previous_x >= right_edge && transform.translation.x < right_edge {
+                        right_edge
+                    } else {
+                        continue;
+                    };
+
+                    transform.translation.x = edge;
+                    if let Some(target) = navigation {
+                        *state = if target.action { State::Action } else { State::Idle };
+                        commands.entity(entity).remove::<Navigation>();
                     }
+                }
+            }
+
+            State::Idle | State::Action | State::Sitting | State::StandingUp => (),
+        }
+    }
+}
+
+// Stretch the interactor box while in the action state next to a prop above or below it, restoring the standing box
+// otherwise.
+fn handle_reach(
+    mut man_query: Query<(&State, &GlobalTransform, &mut Interactor), With<TheMan>>,
+    interactables: Query<(&GlobalTransform, &Interactable)>,
+) {
+    for (state, transform, mut interactor) in &mut man_query {
+        let position = interactor.center(transform);
+        let out_of_reach = *state == State::Action
+            && interactables.iter().any(|(interactable_transform, interactable)| {
+                let interactable_position = interactable.center(interactable_transform);
+                let beside =
+                    (interactable_position.x - position.x).abs() <= (INTERACTOR_WIDTH + interactable.width) / 2.0;
+                let gap = (interactable_position.y - position.y).abs() - interactable.height / 2.0;
+                beside && gap > INTERACTOR_HEIGHT / 2.0 && gap <= REACH_HEIGHT / 2.0
+            });
+
+        let height = if out_of_reach { REACH_HEIGHT } else { INTERACTOR_HEIGHT };
+        if interactor.height != height {
+            interactor.height = height;
+        }
+    }
+}
 
-                    Direction::Up => {}
+// Sit down on a seat interacted with while in the action state, interacting again stands back up.
+fn handle_seat_interaction(
+    mut commands: Commands,
+    config: Res<TheManConfig>,
+    mut events: MessageReader<InteractionEvent>,
+    seats: Query<(&Interactable, &GlobalTransform, &SeatPose)>,
+    mut man_query: Query<(Entity, &mut State, &mut Transform), With<TheMan>>,
+) {
+    for event in events.read() {
+        let Some((_, seat_transform, pose)) = seats.iter().find(|(interactable, ..)| interactable.id == event.id)
+        else {
+            continue;
+        };
+
+        if let Ok((entity, mut state, mut transform)) = man_query.single_mut() {
+            match *state {
+                State::Action => {
+                    // Teleport to the seat's sitting position.
+                    let position = seat_transform.translation().truncate() + pose.offset;
+                    transform.translation.x = position.x;
+                    transform.translation.y = position.y;
+                    transform.rotation = Quat::from_rotation_z(pose.lean);
+
+                    *state = State::Sitting;
+                    commands.entity(entity).insert((
+                        Seated(pose.clone()),
+                        SantaDelay(Timer::from_seconds(config.santa_delay, TimerMode::Once)),
+                    ));
                 }
+
+                State::Sitting => {
+                    transform.rotation = Quat::IDENTITY;
+                    *state = State::StandingUp;
+                }
+
+                _ => {}
+            }
+        }
+    }
+}
+
+// Summon Santa once the man has been sitting for the delay, standing up first cancels the visit. Once the visit limit
+// is reached sitting down no longer summons him.
+fn handle_santa_delay(
+    time: Res<Time>,
+    mut commands: Commands,
+    config: Res<TheManConfig>,
+    mut visits: ResMut<SantaVisits>,
+    mut santa_events: MessageWriter<SantasHereEvent>,
+    mut query: Query<(Entity, &State, &mut SantaDelay), With<TheMan>>,
+) {
+    for (entity, state, mut delay) in &mut query {
+        if *state != State::Sitting {
+            commands.entity(entity).remove::<SantaDelay>();
+            continue;
+        }
+
+        delay.0.tick(time.delta());
+        if delay.0.just_finished() {
+            if config.santa_visit_limit.is_none_or(|limit| visits.0 < limit) {
+                visits.0 += 1;
+                santa_events.write(SantasHereEvent);
             }
+            commands.entity(entity).remove::<SantaDelay>();
+        }
+    }
+}
 
-            State::Idle | State::Action | State::Sitting => (),
+// Stretch when setting off and squash when stopping, easing back to normal. Any other state change mid-tween resets
+// the scale so it can't get stuck.
+fn handle_squash(time: Res<Time>, mut query: Query<(Ref<State>, &mut Squash, &mut Transform), With<TheMan>>) {
+    for (state, mut squash, mut transform) in &mut query {
+        if state.is_changed() {
+            let walking = *state == State::Walking;
+            if walking == squash.walking {
+                squash.timer = None;
+                transform.scale = Vec3::ONE;
+            } else {
+                squash.walking = walking;
+                squash.stretch = if walking { 1.0 } else { -1.0 };
+                squash.timer = Some(Timer::from_seconds(SQUASH_SECONDS, TimerMode::Once));
+            }
+        }
+
+        let stretch = squash.stretch;
+        let Some(timer) = &mut squash.timer else {
+            continue;
+        };
+        timer.tick(time.delta());
+
+        if timer.just_finished() {
+            squash.timer = None;
+            transform.scale = Vec3::ONE;
+        } else {
+            let amount = SQUASH_AMOUNT * (1.0 - timer.fraction()) * stretch;
+            transform.scale = Vec3::new(1.0 - amount, 1.0 + amount, 1.0);
         }
     }
 }
 
+// Place the man on teleport messages, the sprite follows through the usual state change handling.
+fn handle_teleport(
+    mut commands: Commands,
+    mut events: MessageReader<TeleportEvent>,
+    query: Single<(Entity, &mut State, &mut Direction, &mut Transform), With<TheMan>>,
+) {
+    let (entity, mut state, mut direction, mut transform) = query.into_inner();
+
+    for event in events.read() {
+        transform.translation.x = event.position.x.clamp(WALKING_MIN_X, WALKING_MAX_X);
+        transform.translation.y = event.position.y;
+        transform.rotation = Quat::IDENTITY;
+        *state = event.state;
+        *direction = event.direction;
+        commands.entity(entity).remove::<(Navigation, Seated)>();
+    }
+}
+
 // Initialize the man.
 fn init(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
+    sub_pixel: Res<SubPixelRendering>,
+    breath: Res<ColdBreath>,
+    config: Res<TheManConfig>,
     mut texture_layouts: ResMut<Assets<TextureAtlasLayout>>,
 ) {
     // Load the sprite sheets.
@@ -414,6 +962,11 @@ fn init(
         sitting_layout: texture_layouts.add(TextureAtlasLayout::from_grid(UVec2::splat(32), 5, 1, None, None)),
         standing_sprite: asset_server.load("theman/theman_standing.png"),
         standing_layout: texture_layouts.add(TextureAtlasLayout::from_grid(UVec2::splat(32), 1, 1, None, None)),
+        seated_idle: SeatIdle {
+            sprite: asset_server.load("theman/theman_seated_idle.png"),
+            layout: texture_layouts.add(TextureAtlasLayout::from_grid(UVec2::splat(32), 4, 1, None, None)),
+            fps: SEATED_IDLE_FPS,
+        },
     };
     commands.insert_resource(sprites.clone());
 
@@ -443,28 +996,505 @@ fn init(
     commands.insert_resource(audio);
 
     // Create the man starting in the idle state.
-    commands.spawn((
-        Sprite {
-            image: sprites.standing_sprite,
-            texture_atlas: Some(TextureAtlas {
-                layout: sprites.standing_layout,
-                index: 0,
-            }),
+    let man = commands
+        .spawn((
+            Sprite {
+                image: sprites.standing_sprite,
+                texture_atlas: Some(TextureAtlas {
+                    layout: sprites.standing_layout,
+                    index: 0,
+                }),
+                ..default()
+            },
+            Transform::from_translation(Vec3::new(-64.0, -56.0, 0.0)),
+            YSorted {
+                layer: 6.0,
+                foot_offset: -16.0,
+            },
+            TheMan,
+            AnimationConfig::new(0, 8, 10),
+            State::Idle,
+            ActionTimer(Timer::from_seconds(config.action_timeout.max(0.0), TimerMode::Once)),
+            IdleTimer(Timer::from_seconds(5.0, TimerMode::Repeating)),
+            StepTimer(Timer::from_seconds(0.0, TimerMode::Repeating)),
+            BreathTimer(Timer::from_seconds(breath.interval.max(0.01), TimerMode::Repeating)),
+            Direction::Right,
+            FootStep::Left,
+            Squash::default(),
+            // NOTE: SpatialListener::new puts the left ear toward -x for a positive gap, but rodio's spatial source
+            // weights each channel up the farther that ear is from the emitter, so sounds pan to the opposite side.
+            // Negating the gap swaps the ears to cancel that out, the config keeps left ear toward -x as positive.
+            SpatialListener::new(-config.listener_ear_offset),
+            Interactor {
+                width: INTERACTOR_WIDTH,
+                height: INTERACTOR_HEIGHT,
+                offset: INTERACTOR_OFFSET,
+            },
+        ))
+        .id();
+
+    if sub_pixel.enabled {
+        commands.entity(man).insert(SmoothSampling::default());
+    }
+}
+
+// Set the animation to cover every frame of the layout, keeping the current animation if the layout is unusable.
+fn set_animation(
+    config: &mut AnimationConfig,
+    layouts: &Assets<TextureAtlasLayout>,
+    layout: &Handle<TextureAtlasLayout>,
+    fps: u8,
+) {
+    match layouts
+        .get(layout)
+        .and_then(|layout| AnimationConfig::from_layout(layout, fps))
+    {
+        Some(new_config) => *config = new_config,
+        None => warn!("man animation layout is missing or empty, keeping the current animation"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::InputTarget;
+    use bevy::time::TimeUpdateStrategy;
+
+    // Headless app stepping a tenth of a second per update.
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app
+    }
+
+    // Spawn the man with just the components the movement systems need.
+    fn spawn_man(app: &mut App, x: f32, state: State, direction: Direction) -> Entity {
+        app.world_mut()
+            .spawn((
+                TheMan,
+                state,
+                direction,
+                Transform::from_xyz(x, -56.0, 5.0),
+                Interactor {
+                    width: INTERACTOR_WIDTH,
+                    height: INTERACTOR_HEIGHT,
+                    offset: INTERACTOR_OFFSET,
+                },
+            ))
+            .id()
+    }
+
+    // Spawn a lit fire where the fireplace hearth is.
+    fn spawn_fire(app: &mut App) {
+        app.world_mut().spawn((
+            GlobalTransform::from_xyz(116.0, -31.0, 5.0),
+            SolidObstacle { half_width: 8.0 },
+        ));
+    }
+
+    fn update(app: &mut App, frames: usize) {
+        for _ in 0..frames {
+            app.update();
+        }
+    }
+
+    // Ids of the interaction events sent so far.
+    #[derive(Default, Resource)]
+    struct Interactions(Vec<String>);
+
+    fn collect_interactions(mut events: MessageReader<InteractionEvent>, mut interactions: ResMut<Interactions>) {
+        interactions.0.extend(events.read().map(|event| event.id.clone()));
+    }
+
+    // Headless app running the interactions with the feedback sounds left unset.
+    fn interaction_app() -> App {
+        let mut app = test_app();
+        app.add_plugins(AssetPlugin::default())
+            .add_message::<InteractionEvent>()
+            .init_resource::<AudioConfig>()
+            .init_resource::<InteractFeedback>()
+            .init_resource::<InteractionBuffer>()
+            .init_resource::<Interactions>()
+            .add_systems(Update, (handle_interactions, collect_interactions).chain());
+        app
+    }
+
+    // Spawn a prop, in range of the man or not.
+    fn spawn_prop(app: &mut App, id: &str, in_range: bool) -> Entity {
+        let mut prop = app.world_mut().spawn(Interactable {
+            id: id.to_string(),
             ..default()
-        },
-        Transform::from_translation(Vec3::new(-64.0, -56.0, 10.0)),
-        TheMan,
-        AnimationConfig::new(0, 8, 10),
-        State::Idle,
-        IdleTimer(Timer::from_seconds(5.0, TimerMode::Repeating)),
-        StepTimer(Timer::from_seconds(0.0, TimerMode::Repeating)),
-        Direction::Right,
-        FootStep::Left,
-        // NOTE: not sure why the audio width needs to be negative to sound right.
-        SpatialListener::new(-10.0),
-        Interactor {
-            width: 13.0,
-            height: 32.0,
-        },
-    ));
+        });
+        if in_range {
+            prop.insert(InRange { id: id.to_string() });
+        }
+        prop.id()
+    }
+
+    #[test]
+    fn interacting_only_reaches_the_prop_in_range() {
+        let mut app = interaction_app();
+        spawn_prop(&mut app, "chair", true);
+        spawn_prop(&mut app, "fireplace", false);
+        spawn_man(&mut app, 70.0, State::Action, Direction::Right);
+
+        update(&mut app, 3);
+        assert_eq!(app.world().resource::<Interactions>().0, vec!["chair".to_string()]);
+    }
+
+    #[test]
+    fn action_times_out_after_the_configured_duration() {
+        let mut app = test_app();
+        app.insert_resource(TheManConfig {
+            action_timeout: 0.5,
+            ..default()
+        })
+        .add_systems(Update, handle_action_timeout);
+        let man = spawn_man(&mut app, 0.0, State::Idle, Direction::Right);
+        app.world_mut()
+            .entity_mut(man)
+            .insert(ActionTimer(Timer::from_seconds(1.5, TimerMode::Once)));
+
+        // Picks up the configured timeout while idle.
+        update(&mut app, 2);
+        *app.world_mut().get_mut::<State>(man).unwrap() = State::Action;
+        update(&mut app, 3);
+        assert_eq!(*app.world().get::<State>(man).unwrap(), State::Action);
+
+        update(&mut app, 4);
+        assert_eq!(*app.world().get::<State>(man).unwrap(), State::Idle);
+    }
+
+    #[test]
+    fn buffered_interaction_fires_on_walking_into_range() {
+        let mut app = interaction_app();
+        let chair = spawn_prop(&mut app, "chair", false);
+        spawn_man(&mut app, 40.0, State::Action, Direction::Right);
+
+        update(&mut app, 1);
+        assert!(app.world().resource::<InteractionBuffer>().0.is_some());
+
+        app.world_mut().entity_mut(chair).insert(InRange {
+            id: "chair".to_string(),
+        });
+        update(&mut app, 1);
+        assert_eq!(app.world().resource::<Interactions>().0, vec!["chair".to_string()]);
+        assert!(app.world().resource::<InteractionBuffer>().0.is_none());
+    }
+
+    #[test]
+    fn buffered_interaction_expires_without_a_prop_in_range() {
+        let mut app = interaction_app();
+        let chair = spawn_prop(&mut app, "chair", false);
+        spawn_man(&mut app, 40.0, State::Action, Direction::Right);
+
+        // Longer than the buffer before the chair comes in range.
+        update(&mut app, 4);
+        assert!(app.world().resource::<InteractionBuffer>().0.is_none());
+
+        app.world_mut().entity_mut(chair).insert(InRange {
+            id: "chair".to_string(),
+        });
+        update(&mut app, 1);
+        assert!(app.world().resource::<Interactions>().0.is_empty());
+    }
+
+    // Hold a walking direction for a number of frames, returning the man's lowest and highest x on the way.
+    fn hold_direction(app: &mut App, man: Entity, direction: Direction, frames: usize) -> (f32, f32) {
+        let mut range = (f32::MAX, f32::MIN);
+        for _ in 0..frames {
+            app.world_mut().write_message(InputEvent {
+                intent: Intent::Move(direction),
+            });
+            app.update();
+
+            let x = app.world().get::<Transform>(man).unwrap().translation.x;
+            range = (range.0.min(x), range.1.max(x));
+        }
+        range
+    }
+
+    #[test]
+    fn walking_stops_at_the_walking_bounds() {
+        let mut app = test_app();
+        app.add_message::<InputEvent>()
+            .add_systems(Update, (handle_messages, handle_movement).chain());
+        let man = spawn_man(&mut app, 0.0, State::Idle, Direction::Right);
+
+        let (lowest, _) = hold_direction(&mut app, man, Direction::Left, 80);
+        assert_eq!(lowest, WALKING_MIN_X);
+
+        let (_, highest) = hold_direction(&mut app, man, Direction::Right, 120);
+        assert_eq!(highest, WALKING_MAX_X);
+    }
+
+    // Navigate the man from x to the target, returning where he stopped and the state he stopped in.
+    fn navigate(from: f32, target: f32, action: bool) -> (f32, State) {
+        let mut app = test_app();
+        app.add_systems(Update, handle_movement);
+        let direction = if target < from {
+            Direction::Left
+        } else {
+            Direction::Right
+        };
+        let man = spawn_man(&mut app, from, State::Walking, direction);
+        app.world_mut().entity_mut(man).insert(Navigation { x: target, action });
+
+        update(&mut app, 200);
+        assert!(app.world().get::<Navigation>(man).is_none());
+        (
+            app.world().get::<Transform>(man).unwrap().translation.x,
+            *app.world().get::<State>(man).unwrap(),
+        )
+    }
+
+    #[test]
+    fn navigation_ends_on_arrival() {
+        let (x, state) = navigate(0.0, 40.0, false);
+        // He stops on the first step that reaches the target.
+        assert!((40.0..40.0 + WALKING_SPEED * 0.1).contains(&x));
+        assert_eq!(state, State::Idle);
+
+        let (x, state) = navigate(0.0, -40.0, true);
+        assert!((-40.0 - WALKING_SPEED * 0.1..=-40.0).contains(&x));
+        assert_eq!(state, State::Action);
+    }
+
+    #[test]
+    fn navigation_past_the_walking_bounds_ends_at_the_bound() {
+        let (x, state) = navigate(0.0, -500.0, false);
+        assert_eq!(x, WALKING_MIN_X);
+        assert_eq!(state, State::Idle);
+
+        let (x, state) = navigate(0.0, 500.0, false);
+        assert_eq!(x, WALKING_MAX_X);
+        assert_eq!(state, State::Idle);
+    }
+
+    // Send one intent to the man in the given state and handle it.
+    fn handle_intent(state: State, intent: Intent) -> (App, Entity) {
+        let mut app = test_app();
+        app.add_message::<InputEvent>().add_systems(Update, handle_messages);
+        let man = spawn_man(&mut app, 0.0, state, Direction::Right);
+
+        app.world_mut().write_message(InputEvent { intent });
+        app.update();
+        (app, man)
+    }
+
+    #[test]
+    fn moving_mid_action_walks_off() {
+        let (app, man) = handle_intent(State::Action, Intent::Move(Direction::Left));
+
+        assert_eq!(*app.world().get::<State>(man).unwrap(), State::Walking);
+        assert_eq!(*app.world().get::<Direction>(man).unwrap(), Direction::Left);
+    }
+
+    #[test]
+    fn navigating_walks_toward_the_target() {
+        let (app, man) = handle_intent(State::Idle, Intent::Navigate(InputTarget { x: -30.0, action: true }));
+
+        assert_eq!(*app.world().get::<State>(man).unwrap(), State::Walking);
+        assert_eq!(*app.world().get::<Direction>(man).unwrap(), Direction::Left);
+        let navigation = app.world().get::<Navigation>(man).unwrap();
+        assert_eq!((navigation.x, navigation.action), (-30.0, true));
+    }
+
+    #[test]
+    fn navigating_to_where_he_stands_idles() {
+        let (app, man) = handle_intent(State::Walking, Intent::Navigate(InputTarget { x: 0.0, action: false }));
+
+        assert_eq!(*app.world().get::<State>(man).unwrap(), State::Idle);
+        assert!(app.world().get::<Navigation>(man).is_none());
+    }
+
+    #[test]
+    fn idle_look_waits_a_full_interval_after_walking() {
+        let mut app = test_app();
+        app.add_systems(Update, handle_idle_action);
+        let man = spawn_man(&mut app, 0.0, State::Walking, Direction::Right);
+        app.world_mut().entity_mut(man).insert((
+            IdleTimer(Timer::from_seconds(5.0, TimerMode::Repeating)),
+            Sprite::default(),
+        ));
+
+        // Walking for longer than the interval doesn't count toward it.
+        update(&mut app, 60);
+        *app.world_mut().get_mut::<State>(man).unwrap() = State::Idle;
+        update(&mut app, 10);
+        assert!(!app.world().get::<Sprite>(man).unwrap().flip_x);
+
+        update(&mut app, 45);
+        assert!(app.world().get::<Sprite>(man).unwrap().flip_x);
+    }
+
+    #[test]
+    fn walking_faces_the_sprite_along_the_direction() {
+        let mut app = test_app();
+        app.add_message::<InputEvent>()
+            .init_resource::<Assets<TextureAtlasLayout>>()
+            .insert_resource(SpriteAssets {
+                walking_sprite: Handle::default(),
+                walking_layout: Handle::default(),
+                sitting_sprite: Handle::default(),
+                sitting_layout: Handle::default(),
+                standing_sprite: Handle::default(),
+                standing_layout: Handle::default(),
+                seated_idle: SeatIdle {
+                    sprite: Handle::default(),
+                    layout: Handle::default(),
+                    fps: SITTING_FPS,
+                },
+            })
+            .add_systems(Update, (handle_messages, handle_animation_state_change).chain());
+        let man = spawn_man(&mut app, 0.0, State::Idle, Direction::Right);
+        app.world_mut()
+            .entity_mut(man)
+            .insert((Sprite::default(), AnimationConfig::new(0, 0, WALKING_FPS)));
+
+        for (direction, flipped) in [(Direction::Left, true), (Direction::Right, false)] {
+            app.world_mut().write_message(InputEvent {
+                intent: Intent::Move(direction),
+            });
+            app.update();
+            assert_eq!(app.world().get::<Sprite>(man).unwrap().flip_x, flipped, "{direction:?}");
+
+            // Back to idle so the next direction is a fresh state change.
+            app.world_mut().write_message(InputEvent { intent: Intent::Idle });
+            app.update();
+        }
+    }
+
+    // Santa visits summoned so far.
+    #[derive(Default, Resource)]
+    struct Summons(usize);
+
+    fn count_summons(mut events: MessageReader<SantasHereEvent>, mut summons: ResMut<Summons>) {
+        summons.0 += events.read().count();
+    }
+
+    // Sit the man down twice, waiting out the Santa delay each time, and return how many times Santa was summoned.
+    fn summons_after_sitting_twice(santa_visit_limit: Option<u32>) -> usize {
+        let mut app = test_app();
+        app.insert_resource(TheManConfig {
+            santa_visit_limit,
+            ..default()
+        })
+        .add_message::<SantasHereEvent>()
+        .init_resource::<SantaVisits>()
+        .init_resource::<Summons>()
+        .add_systems(Update, (handle_santa_delay, count_summons).chain());
+        let man = spawn_man(&mut app, 70.0, State::Idle, Direction::Right);
+
+        for _ in 0..2 {
+            *app.world_mut().get_mut::<State>(man).unwrap() = State::Sitting;
+            app.world_mut()
+                .entity_mut(man)
+                .insert(SantaDelay(Timer::from_seconds(0.5, TimerMode::Once)));
+            update(&mut app, 10);
+
+            *app.world_mut().get_mut::<State>(man).unwrap() = State::Idle;
+            update(&mut app, 1);
+        }
+        app.world().resource::<Summons>().0
+    }
+
+    #[test]
+    fn santa_visit_limit_stops_later_summons() {
+        assert_eq!(summons_after_sitting_twice(Some(1)), 1);
+        assert_eq!(summons_after_sitting_twice(None), 2);
+    }
+
+    #[test]
+    fn seat_pose_places_and_leans_the_man_until_he_stands() {
+        let mut app = test_app();
+        app.add_message::<InteractionEvent>()
+            .init_resource::<TheManConfig>()
+            .add_systems(Update, handle_seat_interaction);
+        app.world_mut().spawn((
+            Interactable {
+                id: "couch".to_string(),
+                ..default()
+            },
+            GlobalTransform::from_xyz(18.0, -62.0, 0.0),
+            SeatPose {
+                sprite: Handle::default(),
+                layout: Handle::default(),
+                fps: 6,
+                offset: Vec2::new(-10.0, -3.0),
+                lean: 0.35,
+                idle: None,
+            },
+        ));
+        let man = spawn_man(&mut app, 20.0, State::Action, Direction::Right);
+
+        app.world_mut().write_message(InteractionEvent {
+            id: "couch".to_string(),
+        });
+        app.update();
+        let transform = *app.world().get::<Transform>(man).unwrap();
+        assert_eq!(*app.world().get::<State>(man).unwrap(), State::Sitting);
+        assert_eq!(transform.translation.truncate(), Vec2::new(8.0, -65.0));
+        assert!((transform.rotation.to_euler(EulerRot::XYZ).2 - 0.35).abs() < 1e-5);
+
+        app.world_mut().write_message(InteractionEvent {
+            id: "couch".to_string(),
+        });
+        app.update();
+        assert_eq!(*app.world().get::<State>(man).unwrap(), State::StandingUp);
+        assert_eq!(app.world().get::<Transform>(man).unwrap().rotation, Quat::IDENTITY);
+    }
+
+    #[test]
+    fn man_stops_at_the_edge_of_a_lit_fire() {
+        // The edges are the fire's half width plus his own away from the fire's center.
+        let left_edge = 116.0 - 8.0 - INTERACTOR_WIDTH / 2.0;
+        let right_edge = 116.0 + 8.0 + INTERACTOR_WIDTH / 2.0;
+
+        for (x, direction, edge) in [
+            (80.0, Direction::Right, left_edge),
+            (152.0, Direction::Left, right_edge),
+        ] {
+            let mut app = test_app();
+            app.add_systems(Update, handle_movement);
+            spawn_fire(&mut app);
+            let man = spawn_man(&mut app, x, State::Walking, direction);
+
+            update(&mut app, 60);
+            assert_eq!(app.world().get::<Transform>(man).unwrap().translation.x, edge);
+        }
+    }
+
+    #[test]
+    fn man_navigating_into_the_fire_stops_at_its_edge() {
+        let mut app = test_app();
+        app.add_systems(Update, handle_movement);
+        spawn_fire(&mut app);
+        let man = spawn_man(&mut app, 80.0, State::Walking, Direction::Right);
+        app.world_mut()
+            .entity_mut(man)
+            .insert(Navigation { x: 148.0, action: true });
+
+        update(&mut app, 60);
+
+        let world = app.world();
+        assert_eq!(
+            world.get::<Transform>(man).unwrap().translation.x,
+            116.0 - 8.0 - INTERACTOR_WIDTH / 2.0
+        );
+        assert_eq!(*world.get::<State>(man).unwrap(), State::Action);
+        assert!(world.get::<Navigation>(man).is_none());
+    }
+
+    #[test]
+    fn man_walks_out_of_a_fire_lit_around_him() {
+        let mut app = test_app();
+        app.add_systems(Update, handle_movement);
+        spawn_fire(&mut app);
+        let man = spawn_man(&mut app, 110.0, State::Walking, Direction::Right);
+
+        update(&mut app, 30);
+        assert!(app.world().get::<Transform>(man).unwrap().translation.x > 116.0 + 8.0 + INTERACTOR_WIDTH / 2.0);
+    }
 }
@@ -5,8 +5,8 @@ use rand::Rng;
 use crate::{
     animation::AnimationConfig,
     flickering_light::FlickeringLight,
-    interaction::{Interactable, InteractionEvent, State},
-    santa::AddPresentsEvent,
+    interaction::{InitialStates, Interactable, InteractionEvent, State},
+    santa::{AddPresentsEvent, PresentsComingEvent},
 };
 
 #[derive(Clone, Resource)]
@@ -23,7 +23,55 @@ pub struct Tree;
 #[derive(Component)]
 pub struct Presents;
 
-const INTERACTABLE_ID: &str = "tree";
+// Presents left under the tree, the sprite is an asset path and the offset is relative to the tree. The presents glow
+// softly around glow_intensity in glow_color.
+#[derive(Clone, Resource)]
+pub struct PresentsConfig {
+    pub sprite: String,
+    pub offset: Vec2,
+    pub glow_color: Color,
+    pub glow_intensity: f32,
+}
+
+impl Default for PresentsConfig {
+    fn default() -> Self {
+        Self {
+            sprite: "tree/presents.png".to_string(),
+            offset: Vec2::new(20.0, -24.0),
+            glow_color: Color::srgb(1.0, 0.75, 0.4),
+            glow_intensity: 0.3,
+        }
+    }
+}
+
+// Shake the tree gives right before Santa leaves the presents, swaying up to amplitude pixels and settling back over
+// duration seconds. A zero amplitude leaves the tree still.
+#[derive(Clone, Resource)]
+pub struct TreeShake {
+    pub amplitude: f32,
+    pub duration: f32,
+}
+
+impl Default for TreeShake {
+    fn default() -> Self {
+        Self {
+            amplitude: 1.0,
+            duration: 0.6,
+        }
+    }
+}
+
+// Shake in progress, the resting x is restored once the timer finishes.
+#[derive(Component)]
+struct Shaking {
+    timer: Timer,
+    base_x: f32,
+}
+
+pub const INTERACTABLE_ID: &str = "tree";
+
+// Sway speed of the shake in radians per second.
+const SHAKE_FREQUENCY: f32 = 30.0;
 
 // Light effect colors.
 const LIGHT_COLORS: [Color; 4] = [
@@ -35,15 +83,19 @@ const LIGHT_COLORS: [Color; 4] = [
 
 // Add the animation systems.
 pub fn add_systems(app: &mut App) {
-    app.add_systems(Startup, init).add_systems(
-        Update,
-        (
-            handle_animations,
-            handle_interaction,
-            handle_light.in_set(crate::flickering_light::LightInsertionSet),
-            handle_presents_add,
-        ),
-    );
+    app.init_resource::<PresentsConfig>()
+        .init_resource::<TreeShake>()
+        .add_systems(Startup, init)
+        .add_systems(
+            Update,
+            (
+                handle_animations,
+                handle_interaction,
+                handle_light.in_set(crate::flickering_light::LightInsertionSet),
+                handle_presents_add.in_set(crate::flickering_light::LightInsertionSet),
+                handle_shake,
+            ),
+        );
 }
 
 // Manage the animation frame timing.
@@ -135,34 +187,104 @@ fn handle_light(
     }
 }
 
-// Add presents sprite as a child entity to the tree when an AddPresentsEvent is received.
+// Add presents sprite as a child entity to the tree when an AddPresentsEvent is received, the glow is a child of the
+// presents so it goes whenever they do.
 fn handle_presents_add(
     mut commands: Commands,
     sprite_assets: Res<SpriteAssets>,
+    presents_config: Res<PresentsConfig>,
     mut events: MessageReader<AddPresentsEvent>,
     query: Query<(Entity, Option<&Children>), With<Tree>>,
     presents_query: Query<Entity, With<Presents>>,
 ) {
-    for _event in events.read() {
-        for (entity, children) in query.iter() {
-            let has_presents = children.map_or(false, |childs| {
-                childs.iter().any(|child| presents_query.contains(child))
-            });
-
-            if !has_presents {
-                let presents = commands
-                    .spawn((
-                        Sprite {
-                            image: sprite_assets.presents_sprite.clone(),
-                            ..default()
-                        },
-                        Transform::from_xyz(20.0, -24.0, 1.0),
-                        Presents,
-                    ))
-                    .id();
-
-                commands.entity(entity).add_child(presents);
+    // Several events in a frame add one set, presents spawned for the first aren't in the query until the commands run.
+    if events.read().count() == 0 {
+        return;
+    }
+
+    let mut rng = rand::rng();
+
+    for (entity, children) in query.iter() {
+        let has_presents = children.map_or(false, |childs| {
+            childs.iter().any(|child| presents_query.contains(child))
+        });
+
+        if !has_presents {
+            let presents = commands
+                .spawn((
+                    Sprite {
+                        image: sprite_assets.presents_sprite.clone(),
+                        ..default()
+                    },
+                    Transform::from_translation(presents_config.offset.extend(1.0)),
+                    Presents,
+                ))
+                .id();
+
+            commands.entity(entity).add_child(presents);
+
+            let glow = commands
+                .spawn((
+                    Transform::default(),
+                    PointLight2d {
+                        color: presents_config.glow_color,
+                        intensity: 0.0,
+                        radius: 20.0,
+                        ..default()
+                    },
+                    FlickeringLight {
+                        seed: rng.random_range(0.0..1000.0),
+                        intensity_amplitude: presents_config.glow_intensity * 0.2,
+                        intensity_frequency: 0.3,
+                        intensity_min: presents_config.glow_intensity,
+                        intensity_octaves: 1,
+                        color_frequency: 0.0,
+                        color_octaves: 1,
+                        color_seed_offset: 0.0,
+                        color_temperature: 1.0,
+                        colors: vec![presents_config.glow_color],
+                        time_offset: rng.random_range(0.0..100.0),
+                    },
+                ))
+                .id();
+            commands.entity(presents).add_child(glow);
+        }
+    }
+}
+
+// Start shaking the tree when the presents are coming and sway it back to rest, the shake fades out as it finishes.
+fn handle_shake(
+    mut commands: Commands,
+    time: Res<Time>,
+    shake: Res<TreeShake>,
+    mut events: MessageReader<PresentsComingEvent>,
+    mut query: Query<(Entity, &mut Transform, Option<&mut Shaking>), With<Tree>>,
+) {
+    let start = events.read().count() > 0;
+
+    for (entity, mut transform, shaking) in &mut query {
+        match shaking {
+            Some(mut shaking) => {
+                shaking.timer.tick(time.delta());
+
+                if shaking.timer.just_finished() {
+                    transform.translation.x = shaking.base_x;
+                    commands.entity(entity).remove::<Shaking>();
+                } else {
+                    let settle = 1.0 - shaking.timer.fraction();
+                    let sway = (shaking.timer.elapsed_secs() * SHAKE_FREQUENCY).sin();
+                    transform.translation.x = (sway * settle).mul_add(shake.amplitude, shaking.base_x);
+                }
+            }
+
+            // A shake already underway keeps its base so overlapping triggers can't drift the tree.
+            None if start => {
+                commands.entity(entity).insert(Shaking {
+                    timer: Timer::from_seconds(shake.duration, TimerMode::Once),
+                    base_x: transform.translation.x,
+                });
             }
+            None => {}
         }
     }
 }
@@ -171,6 +293,8 @@ fn handle_presents_add(
 fn init(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
+    initial_states: Res<InitialStates>,
+    presents_config: Res<PresentsConfig>,
     mut texture_layouts: ResMut<Assets<TextureAtlasLayout>>,
 ) {
     // Load the running sprite sheet.
@@ -178,21 +302,32 @@ fn init(
         on_sprite: asset_server.load("tree/tree_animation.png"),
         on_layout: texture_layouts.add(TextureAtlasLayout::from_grid(UVec2::splat(64), 5, 1, None, None)),
         off_sprite: asset_server.load("tree/tree.png"),
-        presents_sprite: asset_server.load("tree/presents.png"),
+        presents_sprite: asset_server.load(presents_config.sprite.clone()),
     };
     commands.insert_resource(sprite.clone());
 
-    // Create the sprite starting in the off state.
+    // Create the sprite in its configured starting state, the light follows the state once spawned.
+    let state = initial_states.get(INTERACTABLE_ID);
+    let (image, texture_atlas) = match state {
+        State::On => (
+            sprite.on_sprite,
+            Some(TextureAtlas {
+                layout: sprite.on_layout,
+                index: 0,
+            }),
+        ),
+        State::Off => (sprite.off_sprite, None),
+    };
     commands.spawn((
         Sprite {
-            image: sprite.off_sprite,
-            texture_atlas: None,
+            image,
+            texture_atlas,
             ..default()
         },
         Transform::from_translation(Vec3::new(-58.0, -38.0, 5.0)),
         Tree,
         AnimationConfig::new(0, 4, 2),
-        State::Off,
+        state,
         Interactable {
             id: INTERACTABLE_ID.to_string(),
             height: 64.0,
@@ -210,3 +345,89 @@ fn init(
         },
     ));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn light_flickers_while_on_and_goes_dark_when_off() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins).add_systems(Update, handle_light);
+        let tree = app
+            .world_mut()
+            .spawn((
+                Tree,
+                State::On,
+                PointLight2d {
+                    intensity: 1.0,
+                    ..default()
+                },
+            ))
+            .id();
+
+        app.update();
+        assert!(app.world().get::<FlickeringLight>(tree).is_some());
+
+        *app.world_mut().get_mut::<State>(tree).unwrap() = State::Off;
+        app.update();
+        assert!(app.world().get::<FlickeringLight>(tree).is_none());
+        assert_eq!(app.world().get::<PointLight2d>(tree).unwrap().intensity, 0.0);
+    }
+
+    // Headless app adding presents to a bare tree.
+    fn presents_app() -> (App, Entity) {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_message::<AddPresentsEvent>()
+            .init_resource::<PresentsConfig>()
+            .insert_resource(SpriteAssets {
+                on_sprite: Handle::default(),
+                on_layout: Handle::default(),
+                off_sprite: Handle::default(),
+                presents_sprite: Handle::default(),
+            })
+            .add_systems(Update, handle_presents_add);
+        let tree = app.world_mut().spawn(Tree).id();
+        (app, tree)
+    }
+
+    fn presents(app: &mut App, tree: Entity) -> Vec<Entity> {
+        app.world_mut()
+            .query_filtered::<(Entity, &ChildOf), With<Presents>>()
+            .iter(app.world())
+            .filter(|(_, child_of)| child_of.parent() == tree)
+            .map(|(entity, _)| entity)
+            .collect()
+    }
+
+    #[test]
+    fn presents_are_only_added_once() {
+        let (mut app, tree) = presents_app();
+
+        // Twice in one frame and again in the next.
+        app.world_mut().write_message(AddPresentsEvent);
+        app.world_mut().write_message(AddPresentsEvent);
+        app.update();
+        app.world_mut().write_message(AddPresentsEvent);
+        app.update();
+
+        assert_eq!(presents(&mut app, tree).len(), 1);
+    }
+
+    #[test]
+    fn presents_come_back_after_being_removed() {
+        let (mut app, tree) = presents_app();
+        app.world_mut().write_message(AddPresentsEvent);
+        app.update();
+
+        for entity in presents(&mut app, tree) {
+            app.world_mut().entity_mut(entity).despawn();
+        }
+        assert!(presents(&mut app, tree).is_empty());
+
+        app.world_mut().write_message(AddPresentsEvent);
+        app.update();
+        assert_eq!(presents(&mut app, tree).len(), 1);
+    }
+}
@@ -0,0 +1,64 @@
+use bevy::{prelude::*, transform::TransformSystems};
+
+// Add to entities whose z should follow the y position of their feet, lower entities draw in front within the same
+// layer. The foot offset is from the transform to the bottom of the sprite, minus half the height for a centered one.
+#[derive(Component)]
+pub struct YSorted {
+    pub layer: f32,
+    pub foot_offset: f32,
+}
+
+// Scale applied to the y position, small enough that sorted entities never leave their layer.
+const Y_SCALE: f32 = 0.001;
+
+// Add the y-sort systems.
+pub fn add_systems(app: &mut App) {
+    app.add_systems(PostUpdate, handle_y_sort.before(TransformSystems::Propagate));
+}
+
+// Set the z position of sorted entities from the y position of their feet.
+fn handle_y_sort(mut query: Query<(&mut Transform, &YSorted)>) {
+    for (mut transform, y_sorted) in &mut query {
+        let foot_y = transform.translation.y + y_sorted.foot_offset;
+        transform.translation.z = (-foot_y).mul_add(Y_SCALE, y_sorted.layer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn man_walking_by_draws_in_front_of_the_chair() {
+        let mut app = App::new();
+        app.add_systems(Update, handle_y_sort);
+        let chair = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(70.0, -58.0, 0.0),
+                YSorted {
+                    layer: 6.0,
+                    foot_offset: -12.5,
+                },
+            ))
+            .id();
+        let man = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(70.0, -56.0, 0.0),
+                YSorted {
+                    layer: 6.0,
+                    foot_offset: -16.0,
+                },
+            ))
+            .id();
+
+        app.update();
+
+        // His feet are lower than the chair's even though his center is higher.
+        let world = app.world();
+        assert!(
+            world.get::<Transform>(man).unwrap().translation.z > world.get::<Transform>(chair).unwrap().translation.z
+        );
+    }
+}